@@ -3,24 +3,21 @@ use blindpay::{BlindPay, Result};
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize the client with your API credentials
-    let client = BlindPay::new(
-        "your-api-key-here",
-        "your-instance-id-here"
-    )?;
+    let client = BlindPay::new("your-api-key-here", "your-instance-id-here")?;
 
     // Example 1: Get available rails
     println!("=== Available Rails ===");
     let rails = client.available().get_rails().await?;
     for rail in &rails {
-        println!("{} - {} ({})", rail.label, rail.value, rail.country);
+        println!("{} - {:?} ({})", rail.label, rail.value, rail.country);
     }
 
     // Example 2: List receivers
     println!("\n=== Receivers ===");
-    match client.receivers().list().await {
+    match client.receivers().list(None).await {
         Ok(receivers) => {
-            println!("Found {} receivers", receivers.len());
-            for receiver in receivers.iter().take(5) {
+            println!("Found {} receivers", receivers.data.len());
+            for receiver in receivers.data.iter().take(5) {
                 println!("  - {} ({})", receiver.id, receiver.email);
             }
         }
@@ -35,7 +32,7 @@ async fn main() -> Result<()> {
         Ok(response) => {
             println!("Found {} payouts", response.data.len());
             println!("Has more: {}", response.pagination.has_more);
-            
+
             for payout in response.data.iter().take(3) {
                 println!("  - {} ({:?})", payout.id, payout.status);
             }
@@ -51,8 +48,10 @@ async fn main() -> Result<()> {
         Ok(fees) => {
             println!("Found {} partner fees", fees.len());
             for fee in &fees {
-                println!("  - {}: {} ({}% payout fee)", 
-                    fee.id, fee.name, fee.payout_percentage_fee);
+                println!(
+                    "  - {}: {} ({}% payout fee)",
+                    fee.id, fee.name, fee.payout_percentage_fee
+                );
             }
         }
         Err(e) => {
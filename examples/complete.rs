@@ -1,46 +1,43 @@
-use blindpay::{BlindPay, Result};
 use blindpay::resources::bank_accounts::*;
 use blindpay::resources::quotes::*;
-use blindpay::resources::virtual_accounts::*;
-use blindpay::resources::webhooks::*;
 use blindpay::types::*;
+use blindpay::{Amount, BlindPay, Result};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize the client
-    let client = BlindPay::new(
-        "your-api-key-here",
-        "your-instance-id-here"
-    )?;
+    let client = BlindPay::new("your-api-key-here", "your-instance-id-here")?;
 
     println!("=== BlindPay Rust SDK - Complete Feature Demo ===\n");
 
     // 1. Bank Accounts
     println!("1. Creating PIX bank account...");
-    match client.receivers().bank_accounts().create_pix(CreatePixInput {
-        receiver_id: "re_123".to_string(),
-        name: "My PIX Account".to_string(),
-        pix_key: "14947677768".to_string(),
-    }).await {
+    match client
+        .receivers()
+        .bank_accounts()
+        .create_pix(CreatePixInput {
+            receiver_id: "re_123".to_string(),
+            name: "My PIX Account".to_string(),
+            pix_key: "14947677768".to_string(),
+        })
+        .await
+    {
         Ok(account) => println!("   ✓ Created PIX account: {}", account.id),
         Err(e) => println!("   ✗ Error: {}", e),
     }
 
     // 2. Quotes
     println!("\n2. Creating payout quote...");
-    match client.quotes().create(CreateQuoteInput {
-        bank_account_id: "ba_123".to_string(),
-        currency_type: CurrencyType::Sender,
-        cover_fees: true,
-        request_amount: 1000.0,
-        network: Network::Polygon,
-        token: Some(StablecoinToken::USDC),
-        description: Some("Payment for services".to_string()),
-        partner_fee_id: None,
-        transaction_document_file: None,
-        transaction_document_id: None,
-        transaction_document_type: None,
-    }).await {
+    let quote_input = CreateQuoteInput::builder()
+        .bank_account_id("ba_123")
+        .currency_type(CurrencyType::Sender)
+        .cover_fees(true)
+        .request_amount(Amount::from(1000))
+        .network(Network::Polygon)
+        .token(StablecoinToken::USDC)
+        .description("Payment for services")
+        .build()?;
+    match client.quotes().create(quote_input).await {
         Ok(quote) => {
             println!("   ✓ Quote ID: {}", quote.id);
             println!("   ✓ Sender amount: {}", quote.sender_amount);
@@ -51,12 +48,16 @@ async fn main() -> Result<()> {
 
     // 3. Get FX Rate
     println!("\n3. Getting FX rate...");
-    match client.quotes().get_fx_rate(GetFxRateInput {
-        currency_type: CurrencyType::Sender,
-        from: StablecoinToken::USDC,
-        to: Currency::BRL,
-        request_amount: 1000.0,
-    }).await {
+    match client
+        .quotes()
+        .get_fx_rate(GetFxRateInput {
+            currency_type: CurrencyType::Sender,
+            from: StablecoinToken::USDC,
+            to: Currency::BRL,
+            request_amount: Amount::from(1000),
+        })
+        .await
+    {
         Ok(rate) => {
             println!("   ✓ Commercial rate: {}", rate.commercial_quotation);
             println!("   ✓ BlindPay rate: {}", rate.blindpay_quotation);
@@ -83,7 +84,10 @@ async fn main() -> Result<()> {
         Ok(members) => {
             println!("   ✓ Found {} members", members.len());
             for member in &members {
-                println!("     - {} {} ({:?})", member.first_name, member.last_name, member.role);
+                println!(
+                    "     - {} {} ({:?})",
+                    member.first_name, member.last_name, member.role
+                );
             }
         }
         Err(e) => println!("   ✗ Error: {}", e),
@@ -115,17 +119,22 @@ async fn main() -> Result<()> {
 
     // 8. Payin Quotes
     println!("\n8. Creating payin quote...");
-    match client.payins().quotes().create(CreatePayinQuoteInput {
-        blockchain_wallet_id: "bw_123".to_string(),
-        currency_type: CurrencyType::Sender,
-        payment_method: PayinPaymentMethod::Pix,
-        request_amount: 5000.0,
-        token: StablecoinToken::USDC,
-        is_otc: None,
-        cover_fees: true,
-        partner_fee_id: None,
-        payer_rules: None,
-    }).await {
+    match client
+        .payins()
+        .quotes()
+        .create(CreatePayinQuoteInput {
+            blockchain_wallet_id: "bw_123".to_string(),
+            currency_type: CurrencyType::Sender,
+            payment_method: PayinPaymentMethod::Pix,
+            request_amount: Amount::from(5000),
+            token: StablecoinToken::USDC,
+            is_otc: None,
+            cover_fees: true,
+            partner_fee_id: None,
+            payer_rules: None,
+        })
+        .await
+    {
         Ok(quote) => {
             println!("   ✓ Payin Quote ID: {}", quote.id);
             println!("   ✓ Sender amount: {}", quote.sender_amount);
@@ -1,17 +1,26 @@
-use crate::error::{BlindPayError, Result};
+use crate::error::{ApiError, BlindPayError, Result};
+use crate::middleware::{Middleware, Next};
+use crate::options::{RequestOptions, IDEMPOTENCY_KEY_HEADER};
+use crate::recording::Recorder;
 use crate::resources::{
     available::AvailableResource, instances::InstancesResource, partner_fees::PartnerFeesResource,
     payins::PayinsResource, payouts::PayoutsResource, quotes::QuotesResource,
     receivers::ReceiversResource, virtual_accounts::VirtualAccountsResource,
     wallets::WalletsResources,
 };
-use crate::types::{BlindPayApiResponse, BlindPayErrorResponse, BlindPaySuccessResponse};
-use reqwest::{Client, Method, RequestBuilder};
+use crate::retry::RetryPolicy;
+use crate::types::{BlindPayErrorResponse, BlindPaySuccessResponse, ErrorResponse};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use reqwest::{Client, Method, Proxy, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
 
 const BASE_URL: &str = "https://api.blindpay.com/v1";
 const VERSION: &str = env!("CARGO_PKG_VERSION");
+const REQUEST_ID_HEADER: &str = "x-request-id";
+const MAX_BODY_SNIPPET_LEN: usize = 512;
 
 /// Main BlindPay SDK client
 #[derive(Clone)]
@@ -20,6 +29,12 @@ pub struct BlindPay {
     api_key: String,
     instance_id: String,
     base_url: String,
+    user_agent: String,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    retry_policy: Arc<RetryPolicy>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    recorders: Vec<Arc<dyn Middleware>>,
 }
 
 impl BlindPay {
@@ -36,27 +51,48 @@ impl BlindPay {
     /// let client = BlindPay::new("your-api-key", "your-instance-id").unwrap();
     /// ```
     pub fn new(api_key: impl Into<String>, instance_id: impl Into<String>) -> Result<Self> {
-        let api_key = api_key.into();
-        let instance_id = instance_id.into();
-
-        if api_key.is_empty() {
-            return Err(BlindPayError::MissingApiKey);
-        }
-
-        if instance_id.is_empty() {
-            return Err(BlindPayError::MissingInstanceId);
-        }
+        Self::builder()
+            .api_key(api_key)
+            .instance_id(instance_id)
+            .build()
+    }
 
-        let client = Client::builder()
-            .user_agent(format!("blindpay-rust/{}", VERSION))
-            .build()?;
+    /// Create a builder for a client with custom configuration
+    ///
+    /// # Example
+    /// ```no_run
+    /// use blindpay::BlindPay;
+    /// use std::time::Duration;
+    ///
+    /// let client = BlindPay::builder()
+    ///     .api_key("your-api-key")
+    ///     .instance_id("your-instance-id")
+    ///     .base_url("https://staging.example.com/v1")
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .timeout(Duration::from_secs(30))
+    ///     .user_agent_suffix("payments-service/2.1")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> BlindPayBuilder {
+        BlindPayBuilder::default()
+    }
 
-        Ok(Self {
-            client,
-            api_key,
-            instance_id,
-            base_url: BASE_URL.to_string(),
-        })
+    /// Retry failed requests according to the given policy
+    ///
+    /// Requests are sent once by default.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use blindpay::{BlindPay, RetryPolicy};
+    ///
+    /// let client = BlindPay::new("your-api-key", "your-instance-id")
+    ///     .unwrap()
+    ///     .with_retry_policy(RetryPolicy::default());
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Arc::new(retry_policy);
+        self
     }
 
     /// Get the available resource
@@ -106,7 +142,17 @@ impl BlindPay {
 
     // Internal HTTP methods
     pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        self.request(Method::GET, path, None::<()>).await
+        self.request(Method::GET, path, None::<()>, &RequestOptions::default())
+            .await
+    }
+
+    /// `GET` with `query` URL-encoded into the query string
+    pub(crate) async fn get_with_query<T: DeserializeOwned, Q: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<T> {
+        self.get(&with_query(path, query)?).await
     }
 
     pub(crate) async fn post<T: DeserializeOwned, B: Serialize>(
@@ -114,7 +160,17 @@ impl BlindPay {
         path: &str,
         body: B,
     ) -> Result<T> {
-        self.request(Method::POST, path, Some(body)).await
+        self.post_with_options(path, body, &RequestOptions::default())
+            .await
+    }
+
+    pub(crate) async fn post_with_options<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: B,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        self.request(Method::POST, path, Some(body), options).await
     }
 
     pub(crate) async fn put<T: DeserializeOwned, B: Serialize>(
@@ -122,7 +178,8 @@ impl BlindPay {
         path: &str,
         body: B,
     ) -> Result<T> {
-        self.request(Method::PUT, path, Some(body)).await
+        self.request(Method::PUT, path, Some(body), &RequestOptions::default())
+            .await
     }
 
     pub(crate) async fn patch<T: DeserializeOwned, B: Serialize>(
@@ -130,11 +187,13 @@ impl BlindPay {
         path: &str,
         body: B,
     ) -> Result<T> {
-        self.request(Method::PATCH, path, Some(body)).await
+        self.request(Method::PATCH, path, Some(body), &RequestOptions::default())
+            .await
     }
 
     pub(crate) async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        self.request(Method::DELETE, path, None::<()>).await
+        self.request(Method::DELETE, path, None::<()>, &RequestOptions::default())
+            .await
     }
 
     async fn request<T: DeserializeOwned, B: Serialize>(
@@ -142,33 +201,55 @@ impl BlindPay {
         method: Method,
         path: &str,
         body: Option<B>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        #[cfg(feature = "tracing")]
+        let span = crate::instrumentation::request_span(&method, path);
+        let call = self.send(method, path, body, options);
+        #[cfg(feature = "tracing")]
+        let call = crate::instrumentation::instrument(span, call);
+        call.await
+    }
+
+    async fn send<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<B>,
+        options: &RequestOptions,
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
 
         let mut request = self
             .client
             .request(method, &url)
+            .headers(self.default_headers.clone())
+            .header(USER_AGENT, &self.user_agent)
             .header("Content-Type", "application/json")
             .header("Accept", "application/json")
             .header("Authorization", format!("Bearer {}", self.api_key));
 
-        if let Some(body) = body {
-            request = request.json(&body);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
         }
 
-        let response = request.send().await?;
+        if let Some(key) = options.resolve_idempotency_key() {
+            request = request.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
 
-        if !response.status().is_success() {
-            let error_body: BlindPayErrorResponse = response.json().await?;
-            return Err(BlindPayError::ApiError(error_body.error.message));
+        if let Some(body) = body {
+            request = request.json(&body);
         }
 
-        let api_response: BlindPayApiResponse<T> = response.json().await?;
+        let request = request.build()?;
+        let mut chain: Vec<Arc<dyn Middleware>> = self.middlewares.clone();
+        chain.push(self.retry_policy.clone());
+        #[cfg(feature = "tracing")]
+        chain.push(Arc::new(crate::instrumentation::TraceAttempts));
+        chain.extend(self.recorders.iter().cloned());
+        let response = Next::new(&self.client, &chain).run(request).await?;
 
-        match api_response {
-            BlindPayApiResponse::Success(success) => Ok(success.data),
-            BlindPayApiResponse::Error(error) => Err(BlindPayError::ApiError(error.error.message)),
-        }
+        handle_response(response).await
     }
 
     pub(crate) fn instance_id(&self) -> &str {
@@ -176,10 +257,251 @@ impl BlindPay {
     }
 }
 
+/// Builder for a [`BlindPay`] client, created with [`BlindPay::builder`]
+#[derive(Default)]
+pub struct BlindPayBuilder {
+    api_key: Option<String>,
+    instance_id: Option<String>,
+    base_url: Option<String>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    default_headers: Vec<(String, String)>,
+    user_agent_suffix: Option<String>,
+    http_client: Option<Client>,
+    retry_policy: Option<RetryPolicy>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    recorders: Vec<Arc<dyn Middleware>>,
+}
+
+impl BlindPayBuilder {
+    /// Your BlindPay API key (required)
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Your BlindPay instance ID (required)
+    pub fn instance_id(mut self, instance_id: impl Into<String>) -> Self {
+        self.instance_id = Some(instance_id.into());
+        self
+    }
+
+    /// Send requests to another host, e.g. a staging environment or a local mock
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Maximum time to wait while establishing a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum time for a single request attempt, from sending it until the
+    /// response body has been read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Route all requests through the given proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Add a header sent with every request
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Append an identifier for your application to the `User-Agent` header
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Use an existing `reqwest::Client`, e.g. to share its connection pool
+    ///
+    /// Connect timeouts and proxies are properties of the `reqwest::Client`
+    /// and must be configured on it directly.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Retry failed requests according to the given policy
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Run `middleware` around every request
+    ///
+    /// Middlewares run in registration order, before the retry policy. See
+    /// the [`middleware`](crate::middleware) module.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Record every exchange to a cassette, or replay them from one
+    ///
+    /// Recorders run last, after the retry policy, so each attempt is
+    /// recorded. See the [`recording`](crate::recording) module.
+    pub fn recorder(mut self, recorder: Recorder) -> Self {
+        self.recorders.push(Arc::new(recorder));
+        self
+    }
+
+    pub fn build(self) -> Result<BlindPay> {
+        let api_key = self.api_key.filter(|key| !key.is_empty());
+        let api_key = api_key.ok_or(BlindPayError::MissingApiKey)?;
+        let instance_id = self.instance_id.filter(|id| !id.is_empty());
+        let instance_id = instance_id.ok_or(BlindPayError::MissingInstanceId)?;
+
+        let base_url = match self.base_url {
+            Some(base_url) => {
+                let base_url = base_url.trim_end_matches('/').to_string();
+                if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+                    return Err(BlindPayError::InvalidConfiguration(format!(
+                        "base URL must start with http:// or https://, got `{}`",
+                        base_url
+                    )));
+                }
+                base_url
+            }
+            None => BASE_URL.to_string(),
+        };
+
+        let mut default_headers = HeaderMap::new();
+        for (name, value) in self.default_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                BlindPayError::InvalidConfiguration(format!("invalid header name `{}`", name))
+            })?;
+            let header_value = HeaderValue::from_str(&value).map_err(|_| {
+                BlindPayError::InvalidConfiguration(format!("invalid value for header `{}`", name))
+            })?;
+            default_headers.append(header_name, header_value);
+        }
+
+        let user_agent = match self.user_agent_suffix {
+            Some(suffix) => format!("blindpay-rust/{} {}", VERSION, suffix),
+            None => format!("blindpay-rust/{}", VERSION),
+        };
+        HeaderValue::from_str(&user_agent).map_err(|_| {
+            BlindPayError::InvalidConfiguration("invalid user agent suffix".to_string())
+        })?;
+
+        let client = match self.http_client {
+            Some(_) if self.proxy.is_some() || self.connect_timeout.is_some() => {
+                return Err(BlindPayError::InvalidConfiguration(
+                    "proxy and connect_timeout must be configured on the custom http client"
+                        .to_string(),
+                ));
+            }
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(BlindPay {
+            client,
+            api_key,
+            instance_id,
+            base_url,
+            user_agent,
+            default_headers,
+            timeout: self.timeout,
+            retry_policy: Arc::new(self.retry_policy.unwrap_or_else(RetryPolicy::none)),
+            middlewares: self.middlewares,
+            recorders: self.recorders,
+        })
+    }
+}
+
+async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let status = response.status();
+    let headers = response.headers().clone();
+    let text = response.text().await?;
+
+    if !status.is_success() {
+        return Err(match serde_json::from_str::<BlindPayErrorResponse>(&text) {
+            Ok(error_body) => api_error(status, &headers, error_body.error),
+            Err(_) => BlindPayError::UnexpectedResponse {
+                status,
+                body_snippet: body_snippet(&text),
+            },
+        });
+    }
+
+    // Parsed straight from the text rather than through the untagged
+    // `BlindPayApiResponse`, which would buffer amounts as `f64`
+    if let Ok(error_body) = serde_json::from_str::<BlindPayErrorResponse>(&text) {
+        return Err(api_error(status, &headers, error_body.error));
+    }
+    let success: BlindPaySuccessResponse<T> = serde_json::from_str(&text)?;
+    Ok(success.data)
+}
+
+fn api_error(status: StatusCode, headers: &HeaderMap, error: ErrorResponse) -> BlindPayError {
+    let request_id = headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    BlindPayError::ApiError(Box::new(ApiError {
+        status,
+        message: error.message,
+        code: error.code,
+        errors: error.errors.unwrap_or_default(),
+        request_id,
+    }))
+}
+
+fn body_snippet(body: &str) -> String {
+    let body = body.trim();
+    match body.char_indices().nth(MAX_BODY_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+/// Append `query` to `path` as a URL-encoded query string.
+///
+/// `None` fields are skipped, so an empty set of parameters leaves the path
+/// untouched.
+pub(crate) fn with_query<Q: Serialize + ?Sized>(path: &str, query: &Q) -> Result<String> {
+    let query = serde_urlencoded::to_string(query)?;
+    if query.is_empty() {
+        Ok(path.to_string())
+    } else {
+        Ok(format!("{}?{}", path, query))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn test_client(server: &mockito::Server) -> BlindPayBuilder {
+        BlindPay::builder()
+            .api_key("test-api-key")
+            .instance_id("in_123")
+            .base_url(server.url())
+    }
+
     #[test]
     fn test_new_client() {
         let client = BlindPay::new("test-api-key", "test-instance-id");
@@ -197,4 +519,492 @@ mod tests {
         let client = BlindPay::new("test-api-key", "");
         assert!(matches!(client, Err(BlindPayError::MissingInstanceId)));
     }
+
+    #[test]
+    fn test_builder_requires_credentials() {
+        let missing_key = BlindPay::builder().instance_id("in_123").build();
+        assert!(matches!(missing_key, Err(BlindPayError::MissingApiKey)));
+
+        let missing_instance = BlindPay::builder().api_key("test-api-key").build();
+        assert!(matches!(
+            missing_instance,
+            Err(BlindPayError::MissingInstanceId)
+        ));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_configuration() {
+        let builder = || BlindPay::builder().api_key("key").instance_id("in_123");
+
+        let bad_url = builder().base_url("api.blindpay.com").build();
+        assert!(matches!(
+            bad_url,
+            Err(BlindPayError::InvalidConfiguration(_))
+        ));
+
+        let bad_header = builder().default_header("bad header", "value").build();
+        assert!(matches!(
+            bad_header,
+            Err(BlindPayError::InvalidConfiguration(_))
+        ));
+
+        let proxy_with_client = builder()
+            .http_client(Client::new())
+            .proxy(Proxy::all("http://localhost:8080").unwrap())
+            .build();
+        assert!(matches!(
+            proxy_with_client,
+            Err(BlindPayError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_builder_sends_configured_headers() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .match_header("x-team", "payments")
+            .match_header(
+                "user-agent",
+                format!("blindpay-rust/{} payments-service/2.1", VERSION).as_str(),
+            )
+            .match_header("authorization", "Bearer test-api-key")
+            .with_body(r#"{"data":[],"error":null}"#)
+            .create_async()
+            .await;
+
+        let client = BlindPay::builder()
+            .api_key("test-api-key")
+            .instance_id("in_123")
+            .base_url(format!("{}/", server.url()))
+            .default_header("x-team", "payments")
+            .user_agent_suffix("payments-service/2.1")
+            .http_client(Client::new())
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        client.partner_fees().list().await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_api_error_preserves_details() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/instances/in_123/receivers/re_404")
+            .with_status(404)
+            .with_header("x-request-id", "req_abc")
+            .with_body(
+                r#"{"data":null,"error":{"message":"Receiver not found","code":"not_found","errors":[{"field":"receiver_id","message":"unknown id"}]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = test_client(&server).build().unwrap();
+
+        let err = client.receivers().get("re_404").await.unwrap_err();
+        assert!(err.is_not_found());
+        assert!(!err.is_retryable());
+        assert_eq!(err.request_id(), Some("req_abc"));
+
+        let BlindPayError::ApiError(details) = err else {
+            panic!("expected an API error");
+        };
+        assert_eq!(details.status, StatusCode::NOT_FOUND);
+        assert_eq!(details.message, "Receiver not found");
+        assert_eq!(details.code.as_deref(), Some("not_found"));
+        assert_eq!(details.errors[0].field, "receiver_id");
+    }
+
+    #[tokio::test]
+    async fn test_non_json_error_body() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .with_status(502)
+            .with_header("content-type", "text/html")
+            .with_body("<html><body>502 Bad Gateway</body></html>")
+            .create_async()
+            .await;
+
+        let client = test_client(&server).build().unwrap();
+
+        let err = client.partner_fees().list().await.unwrap_err();
+        assert!(err.is_retryable());
+        match err {
+            BlindPayError::UnexpectedResponse {
+                status,
+                body_snippet,
+            } => {
+                assert_eq!(status, StatusCode::BAD_GATEWAY);
+                assert!(body_snippet.contains("502 Bad Gateway"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_error_body() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("DELETE", "/instances/in_123/partner-fees/pf_123")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let client = test_client(&server).build().unwrap();
+
+        let err = client.partner_fees().delete("pf_123").await.unwrap_err();
+        assert!(matches!(
+            err,
+            BlindPayError::UnexpectedResponse { status, ref body_snippet }
+                if status == StatusCode::SERVICE_UNAVAILABLE && body_snippet.is_empty()
+        ));
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        RetryPolicy::default()
+            .base_delay(std::time::Duration::from_millis(1))
+            .jitter(false)
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let mut server = mockito::Server::new_async().await;
+        let failures = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .with_status(503)
+            .with_body("Service Unavailable")
+            .expect(2)
+            .create_async()
+            .await;
+        let success = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .with_status(200)
+            .with_body(r#"{"data":[],"error":null}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = test_client(&server)
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        let fees = client.partner_fees().list().await.unwrap();
+        assert!(fees.is_empty());
+        failures.assert_async().await;
+        success.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retries_give_up_after_max_attempts() {
+        let mut server = mockito::Server::new_async().await;
+        let failures = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .with_body(r#"{"data":null,"error":{"message":"Too many requests"}}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = test_client(&server)
+            .retry_policy(fast_retries().max_attempts(2))
+            .build()
+            .unwrap();
+
+        let err = client.partner_fees().list().await.unwrap_err();
+        assert!(err.is_rate_limited());
+        failures.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_without_idempotency_key_is_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let failures = server
+            .mock("POST", "/instances/in_123/payouts/evm")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = test_client(&server)
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        let err = client
+            .payouts()
+            .create_evm("qu_123", "0x123")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        failures.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_with_idempotency_key_is_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let failure = server
+            .mock("POST", "/instances/in_123/payouts/evm")
+            .match_header("idempotency-key", "payout-42")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+        let success = server
+            .mock("POST", "/instances/in_123/payouts/evm")
+            .match_header("idempotency-key", "payout-42")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"id":"pa_123","status":"processing","sender_wallet_address":"0x123","receiver_id":"re_123","tracking_complete":null,"tracking_payment":null,"tracking_transaction":null,"tracking_partner_fee":null,"tracking_liquidity":null},"error":null}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = test_client(&server)
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        let payout = client
+            .payouts()
+            .with_options(RequestOptions::new().idempotency_key("payout-42"))
+            .create_evm("qu_123", "0x123")
+            .await
+            .unwrap();
+        assert_eq!(payout.id, "pa_123");
+        failure.assert_async().await;
+        success.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_auto_idempotency_key_is_sent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/instances/in_123/quotes")
+            .match_header(
+                "idempotency-key",
+                mockito::Matcher::Regex("^[0-9a-f-]{36}$".to_string()),
+            )
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = test_client(&server)
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        let input = serde_json::from_value(serde_json::json!({
+            "bank_account_id": "ba_123",
+            "currency_type": "sender",
+            "cover_fees": true,
+            "request_amount": 1000.0,
+            "network": "polygon",
+            "token": null,
+            "description": null,
+            "partner_fee_id": null,
+            "transaction_document_file": null,
+            "transaction_document_id": null,
+            "transaction_document_type": null
+        }))
+        .unwrap();
+        let err = client
+            .quotes()
+            .with_options(RequestOptions::new().auto_idempotency_key())
+            .create(input)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        mock.assert_async().await;
+    }
+
+    /// Appends its name and the attempt number to a shared log
+    struct Log {
+        name: &'static str,
+        entries: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Middleware for Log {
+        async fn handle(&self, request: reqwest::Request, next: Next<'_>) -> Result<Response> {
+            let entry = format!("{} {}", self.name, next.attempt());
+            self.entries.lock().unwrap().push(entry);
+            next.run(request).await
+        }
+    }
+
+    struct RotateKey(&'static str);
+
+    #[async_trait::async_trait]
+    impl Middleware for RotateKey {
+        async fn handle(&self, mut request: reqwest::Request, next: Next<'_>) -> Result<Response> {
+            let value = HeaderValue::from_str(&format!("Bearer {}", self.0)).unwrap();
+            request
+                .headers_mut()
+                .insert(reqwest::header::AUTHORIZATION, value);
+            next.run(request).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middlewares_run_in_order_around_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let failure = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .match_header("authorization", "Bearer rotated-key")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let success = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .match_header("authorization", "Bearer rotated-key")
+            .with_body(r#"{"data":[],"error":null}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let entries = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = |name| Log {
+            name,
+            entries: entries.clone(),
+        };
+        // A policy registered as a middleware reports its attempts downstream
+        let client = test_client(&server)
+            .middleware(log("outer"))
+            .middleware(RotateKey("rotated-key"))
+            .middleware(fast_retries())
+            .middleware(log("inner"))
+            .build()
+            .unwrap();
+
+        client.partner_fees().list().await.unwrap();
+        assert_eq!(*entries.lock().unwrap(), ["outer 1", "inner 1", "inner 2"]);
+        failure.assert_async().await;
+        success.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_middleware_can_answer_without_network() {
+        struct Canned;
+
+        #[async_trait::async_trait]
+        impl Middleware for Canned {
+            async fn handle(&self, _: reqwest::Request, _: Next<'_>) -> Result<Response> {
+                let body = r#"{"data":[],"error":null}"#;
+                Ok(Response::from(http::Response::new(body)))
+            }
+        }
+
+        let client = BlindPay::builder()
+            .api_key("test-api-key")
+            .instance_id("in_123")
+            .base_url("http://127.0.0.1:9")
+            .middleware(Canned)
+            .build()
+            .unwrap();
+
+        assert!(client.partner_fees().list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_auto_idempotency_key_middleware_makes_posts_retryable() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/instances/in_123/payouts/evm")
+            .match_header(
+                "idempotency-key",
+                mockito::Matcher::Regex("^[0-9a-f-]{36}$".to_string()),
+            )
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = test_client(&server)
+            .middleware(crate::middleware::AutoIdempotencyKey)
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        let err = client
+            .payouts()
+            .create_evm("qu_123", "0x123")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_body_snippet_is_truncated() {
+        let body = "é".repeat(MAX_BODY_SNIPPET_LEN + 10);
+        let snippet = body_snippet(&body);
+        assert_eq!(snippet.chars().count(), MAX_BODY_SNIPPET_LEN + 3);
+        assert!(snippet.ends_with("..."));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_error_is_retryable() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/instances/in_123/partner-fees")
+            .with_status(429)
+            .with_body(r#"{"data":null,"error":{"message":"Too many requests"}}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server).build().unwrap();
+
+        let err = client.partner_fees().list().await.unwrap_err();
+        assert!(err.is_rate_limited());
+        assert!(err.is_retryable());
+        assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+    }
+
+    #[test]
+    fn test_with_query_encodes_every_pagination_field() {
+        let params = crate::types::PaginationParams {
+            limit: Some(50),
+            offset: Some(10),
+            starting_after: Some("pa_1&x=y".to_string()),
+            ending_before: Some("pa 2".to_string()),
+        };
+        assert_eq!(
+            with_query("/payouts", &params).unwrap(),
+            "/payouts?limit=50&offset=10&starting_after=pa_1%26x%3Dy&ending_before=pa+2"
+        );
+        assert_eq!(
+            with_query("/payouts", &crate::types::PaginationParams::default()).unwrap(),
+            "/payouts"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bank_details_sends_rail_query() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/available/bank-details")
+            .match_query(mockito::Matcher::Exact("rail=pix".into()))
+            .with_status(200)
+            .with_body(r#"{"data":[],"error":null}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server).build().unwrap();
+
+        let details = client
+            .available()
+            .get_bank_details(crate::types::Rail::Pix)
+            .await
+            .unwrap();
+        assert!(details.is_empty());
+        mock.assert_async().await;
+    }
 }
@@ -0,0 +1,1106 @@
+use crate::macros::api_enum;
+use crate::types::{Currency, Rail};
+
+api_enum! {
+    /// ISO 3166-1 country, sent as its alpha-2 code
+    pub enum Country {
+        /// Andorra
+        AD => "AD",
+        /// United Arab Emirates
+        AE => "AE",
+        /// Afghanistan
+        AF => "AF",
+        /// Antigua and Barbuda
+        AG => "AG",
+        /// Anguilla
+        AI => "AI",
+        /// Albania
+        AL => "AL",
+        /// Armenia
+        AM => "AM",
+        /// Angola
+        AO => "AO",
+        /// Antarctica
+        AQ => "AQ",
+        /// Argentina
+        AR => "AR",
+        /// American Samoa
+        AS => "AS",
+        /// Austria
+        AT => "AT",
+        /// Australia
+        AU => "AU",
+        /// Aruba
+        AW => "AW",
+        /// Åland Islands
+        AX => "AX",
+        /// Azerbaijan
+        AZ => "AZ",
+        /// Bosnia and Herzegovina
+        BA => "BA",
+        /// Barbados
+        BB => "BB",
+        /// Bangladesh
+        BD => "BD",
+        /// Belgium
+        BE => "BE",
+        /// Burkina Faso
+        BF => "BF",
+        /// Bulgaria
+        BG => "BG",
+        /// Bahrain
+        BH => "BH",
+        /// Burundi
+        BI => "BI",
+        /// Benin
+        BJ => "BJ",
+        /// Saint Barthélemy
+        BL => "BL",
+        /// Bermuda
+        BM => "BM",
+        /// Brunei Darussalam
+        BN => "BN",
+        /// Bolivia
+        BO => "BO",
+        /// Bonaire, Sint Eustatius and Saba
+        BQ => "BQ",
+        /// Brazil
+        BR => "BR",
+        /// Bahamas
+        BS => "BS",
+        /// Bhutan
+        BT => "BT",
+        /// Bouvet Island
+        BV => "BV",
+        /// Botswana
+        BW => "BW",
+        /// Belarus
+        BY => "BY",
+        /// Belize
+        BZ => "BZ",
+        /// Canada
+        CA => "CA",
+        /// Cocos (Keeling) Islands
+        CC => "CC",
+        /// Congo, The Democratic Republic of the
+        CD => "CD",
+        /// Central African Republic
+        CF => "CF",
+        /// Congo
+        CG => "CG",
+        /// Switzerland
+        CH => "CH",
+        /// Côte d'Ivoire
+        CI => "CI",
+        /// Cook Islands
+        CK => "CK",
+        /// Chile
+        CL => "CL",
+        /// Cameroon
+        CM => "CM",
+        /// China
+        CN => "CN",
+        /// Colombia
+        CO => "CO",
+        /// Costa Rica
+        CR => "CR",
+        /// Cuba
+        CU => "CU",
+        /// Cabo Verde
+        CV => "CV",
+        /// Curaçao
+        CW => "CW",
+        /// Christmas Island
+        CX => "CX",
+        /// Cyprus
+        CY => "CY",
+        /// Czechia
+        CZ => "CZ",
+        /// Germany
+        DE => "DE",
+        /// Djibouti
+        DJ => "DJ",
+        /// Denmark
+        DK => "DK",
+        /// Dominica
+        DM => "DM",
+        /// Dominican Republic
+        DO => "DO",
+        /// Algeria
+        DZ => "DZ",
+        /// Ecuador
+        EC => "EC",
+        /// Estonia
+        EE => "EE",
+        /// Egypt
+        EG => "EG",
+        /// Western Sahara
+        EH => "EH",
+        /// Eritrea
+        ER => "ER",
+        /// Spain
+        ES => "ES",
+        /// Ethiopia
+        ET => "ET",
+        /// Finland
+        FI => "FI",
+        /// Fiji
+        FJ => "FJ",
+        /// Falkland Islands (Malvinas)
+        FK => "FK",
+        /// Micronesia, Federated States of
+        FM => "FM",
+        /// Faroe Islands
+        FO => "FO",
+        /// France
+        FR => "FR",
+        /// Gabon
+        GA => "GA",
+        /// United Kingdom
+        GB => "GB",
+        /// Grenada
+        GD => "GD",
+        /// Georgia
+        GE => "GE",
+        /// French Guiana
+        GF => "GF",
+        /// Guernsey
+        GG => "GG",
+        /// Ghana
+        GH => "GH",
+        /// Gibraltar
+        GI => "GI",
+        /// Greenland
+        GL => "GL",
+        /// Gambia
+        GM => "GM",
+        /// Guinea
+        GN => "GN",
+        /// Guadeloupe
+        GP => "GP",
+        /// Equatorial Guinea
+        GQ => "GQ",
+        /// Greece
+        GR => "GR",
+        /// South Georgia and the South Sandwich Islands
+        GS => "GS",
+        /// Guatemala
+        GT => "GT",
+        /// Guam
+        GU => "GU",
+        /// Guinea-Bissau
+        GW => "GW",
+        /// Guyana
+        GY => "GY",
+        /// Hong Kong
+        HK => "HK",
+        /// Heard Island and McDonald Islands
+        HM => "HM",
+        /// Honduras
+        HN => "HN",
+        /// Croatia
+        HR => "HR",
+        /// Haiti
+        HT => "HT",
+        /// Hungary
+        HU => "HU",
+        /// Indonesia
+        ID => "ID",
+        /// Ireland
+        IE => "IE",
+        /// Israel
+        IL => "IL",
+        /// Isle of Man
+        IM => "IM",
+        /// India
+        IN => "IN",
+        /// British Indian Ocean Territory
+        IO => "IO",
+        /// Iraq
+        IQ => "IQ",
+        /// Iran
+        IR => "IR",
+        /// Iceland
+        IS => "IS",
+        /// Italy
+        IT => "IT",
+        /// Jersey
+        JE => "JE",
+        /// Jamaica
+        JM => "JM",
+        /// Jordan
+        JO => "JO",
+        /// Japan
+        JP => "JP",
+        /// Kenya
+        KE => "KE",
+        /// Kyrgyzstan
+        KG => "KG",
+        /// Cambodia
+        KH => "KH",
+        /// Kiribati
+        KI => "KI",
+        /// Comoros
+        KM => "KM",
+        /// Saint Kitts and Nevis
+        KN => "KN",
+        /// North Korea
+        KP => "KP",
+        /// South Korea
+        KR => "KR",
+        /// Kuwait
+        KW => "KW",
+        /// Cayman Islands
+        KY => "KY",
+        /// Kazakhstan
+        KZ => "KZ",
+        /// Laos
+        LA => "LA",
+        /// Lebanon
+        LB => "LB",
+        /// Saint Lucia
+        LC => "LC",
+        /// Liechtenstein
+        LI => "LI",
+        /// Sri Lanka
+        LK => "LK",
+        /// Liberia
+        LR => "LR",
+        /// Lesotho
+        LS => "LS",
+        /// Lithuania
+        LT => "LT",
+        /// Luxembourg
+        LU => "LU",
+        /// Latvia
+        LV => "LV",
+        /// Libya
+        LY => "LY",
+        /// Morocco
+        MA => "MA",
+        /// Monaco
+        MC => "MC",
+        /// Moldova
+        MD => "MD",
+        /// Montenegro
+        ME => "ME",
+        /// Saint Martin (French part)
+        MF => "MF",
+        /// Madagascar
+        MG => "MG",
+        /// Marshall Islands
+        MH => "MH",
+        /// North Macedonia
+        MK => "MK",
+        /// Mali
+        ML => "ML",
+        /// Myanmar
+        MM => "MM",
+        /// Mongolia
+        MN => "MN",
+        /// Macao
+        MO => "MO",
+        /// Northern Mariana Islands
+        MP => "MP",
+        /// Martinique
+        MQ => "MQ",
+        /// Mauritania
+        MR => "MR",
+        /// Montserrat
+        MS => "MS",
+        /// Malta
+        MT => "MT",
+        /// Mauritius
+        MU => "MU",
+        /// Maldives
+        MV => "MV",
+        /// Malawi
+        MW => "MW",
+        /// Mexico
+        MX => "MX",
+        /// Malaysia
+        MY => "MY",
+        /// Mozambique
+        MZ => "MZ",
+        /// Namibia
+        NA => "NA",
+        /// New Caledonia
+        NC => "NC",
+        /// Niger
+        NE => "NE",
+        /// Norfolk Island
+        NF => "NF",
+        /// Nigeria
+        NG => "NG",
+        /// Nicaragua
+        NI => "NI",
+        /// Netherlands
+        NL => "NL",
+        /// Norway
+        NO => "NO",
+        /// Nepal
+        NP => "NP",
+        /// Nauru
+        NR => "NR",
+        /// Niue
+        NU => "NU",
+        /// New Zealand
+        NZ => "NZ",
+        /// Oman
+        OM => "OM",
+        /// Panama
+        PA => "PA",
+        /// Peru
+        PE => "PE",
+        /// French Polynesia
+        PF => "PF",
+        /// Papua New Guinea
+        PG => "PG",
+        /// Philippines
+        PH => "PH",
+        /// Pakistan
+        PK => "PK",
+        /// Poland
+        PL => "PL",
+        /// Saint Pierre and Miquelon
+        PM => "PM",
+        /// Pitcairn
+        PN => "PN",
+        /// Puerto Rico
+        PR => "PR",
+        /// Palestine, State of
+        PS => "PS",
+        /// Portugal
+        PT => "PT",
+        /// Palau
+        PW => "PW",
+        /// Paraguay
+        PY => "PY",
+        /// Qatar
+        QA => "QA",
+        /// Réunion
+        RE => "RE",
+        /// Romania
+        RO => "RO",
+        /// Serbia
+        RS => "RS",
+        /// Russian Federation
+        RU => "RU",
+        /// Rwanda
+        RW => "RW",
+        /// Saudi Arabia
+        SA => "SA",
+        /// Solomon Islands
+        SB => "SB",
+        /// Seychelles
+        SC => "SC",
+        /// Sudan
+        SD => "SD",
+        /// Sweden
+        SE => "SE",
+        /// Singapore
+        SG => "SG",
+        /// Saint Helena, Ascension and Tristan da Cunha
+        SH => "SH",
+        /// Slovenia
+        SI => "SI",
+        /// Svalbard and Jan Mayen
+        SJ => "SJ",
+        /// Slovakia
+        SK => "SK",
+        /// Sierra Leone
+        SL => "SL",
+        /// San Marino
+        SM => "SM",
+        /// Senegal
+        SN => "SN",
+        /// Somalia
+        SO => "SO",
+        /// Suriname
+        SR => "SR",
+        /// South Sudan
+        SS => "SS",
+        /// Sao Tome and Principe
+        ST => "ST",
+        /// El Salvador
+        SV => "SV",
+        /// Sint Maarten (Dutch part)
+        SX => "SX",
+        /// Syria
+        SY => "SY",
+        /// Eswatini
+        SZ => "SZ",
+        /// Turks and Caicos Islands
+        TC => "TC",
+        /// Chad
+        TD => "TD",
+        /// French Southern Territories
+        TF => "TF",
+        /// Togo
+        TG => "TG",
+        /// Thailand
+        TH => "TH",
+        /// Tajikistan
+        TJ => "TJ",
+        /// Tokelau
+        TK => "TK",
+        /// Timor-Leste
+        TL => "TL",
+        /// Turkmenistan
+        TM => "TM",
+        /// Tunisia
+        TN => "TN",
+        /// Tonga
+        TO => "TO",
+        /// Türkiye
+        TR => "TR",
+        /// Trinidad and Tobago
+        TT => "TT",
+        /// Tuvalu
+        TV => "TV",
+        /// Taiwan
+        TW => "TW",
+        /// Tanzania
+        TZ => "TZ",
+        /// Ukraine
+        UA => "UA",
+        /// Uganda
+        UG => "UG",
+        /// United States Minor Outlying Islands
+        UM => "UM",
+        /// United States
+        US => "US",
+        /// Uruguay
+        UY => "UY",
+        /// Uzbekistan
+        UZ => "UZ",
+        /// Holy See (Vatican City State)
+        VA => "VA",
+        /// Saint Vincent and the Grenadines
+        VC => "VC",
+        /// Venezuela
+        VE => "VE",
+        /// Virgin Islands, British
+        VG => "VG",
+        /// Virgin Islands, U.S.
+        VI => "VI",
+        /// Vietnam
+        VN => "VN",
+        /// Vanuatu
+        VU => "VU",
+        /// Wallis and Futuna
+        WF => "WF",
+        /// Samoa
+        WS => "WS",
+        /// Yemen
+        YE => "YE",
+        /// Mayotte
+        YT => "YT",
+        /// South Africa
+        ZA => "ZA",
+        /// Zambia
+        ZM => "ZM",
+        /// Zimbabwe
+        ZW => "ZW",
+    }
+}
+
+const US_RAILS: &[Rail] = &[Rail::Ach, Rail::Wire, Rail::Rtp];
+const BR_RAILS: &[Rail] = &[Rail::Pix];
+const MX_RAILS: &[Rail] = &[Rail::SpeiBitso];
+const AR_RAILS: &[Rail] = &[Rail::TransfersBitso];
+const CO_RAILS: &[Rail] = &[Rail::AchCopBitso];
+const SWIFT_RAILS: &[Rail] = &[Rail::InternationalSwift];
+
+impl Country {
+    /// Every country in ISO 3166-1, ordered by alpha-2 code
+    pub const ALL: &'static [Country] = &[
+        Country::AD,
+        Country::AE,
+        Country::AF,
+        Country::AG,
+        Country::AI,
+        Country::AL,
+        Country::AM,
+        Country::AO,
+        Country::AQ,
+        Country::AR,
+        Country::AS,
+        Country::AT,
+        Country::AU,
+        Country::AW,
+        Country::AX,
+        Country::AZ,
+        Country::BA,
+        Country::BB,
+        Country::BD,
+        Country::BE,
+        Country::BF,
+        Country::BG,
+        Country::BH,
+        Country::BI,
+        Country::BJ,
+        Country::BL,
+        Country::BM,
+        Country::BN,
+        Country::BO,
+        Country::BQ,
+        Country::BR,
+        Country::BS,
+        Country::BT,
+        Country::BV,
+        Country::BW,
+        Country::BY,
+        Country::BZ,
+        Country::CA,
+        Country::CC,
+        Country::CD,
+        Country::CF,
+        Country::CG,
+        Country::CH,
+        Country::CI,
+        Country::CK,
+        Country::CL,
+        Country::CM,
+        Country::CN,
+        Country::CO,
+        Country::CR,
+        Country::CU,
+        Country::CV,
+        Country::CW,
+        Country::CX,
+        Country::CY,
+        Country::CZ,
+        Country::DE,
+        Country::DJ,
+        Country::DK,
+        Country::DM,
+        Country::DO,
+        Country::DZ,
+        Country::EC,
+        Country::EE,
+        Country::EG,
+        Country::EH,
+        Country::ER,
+        Country::ES,
+        Country::ET,
+        Country::FI,
+        Country::FJ,
+        Country::FK,
+        Country::FM,
+        Country::FO,
+        Country::FR,
+        Country::GA,
+        Country::GB,
+        Country::GD,
+        Country::GE,
+        Country::GF,
+        Country::GG,
+        Country::GH,
+        Country::GI,
+        Country::GL,
+        Country::GM,
+        Country::GN,
+        Country::GP,
+        Country::GQ,
+        Country::GR,
+        Country::GS,
+        Country::GT,
+        Country::GU,
+        Country::GW,
+        Country::GY,
+        Country::HK,
+        Country::HM,
+        Country::HN,
+        Country::HR,
+        Country::HT,
+        Country::HU,
+        Country::ID,
+        Country::IE,
+        Country::IL,
+        Country::IM,
+        Country::IN,
+        Country::IO,
+        Country::IQ,
+        Country::IR,
+        Country::IS,
+        Country::IT,
+        Country::JE,
+        Country::JM,
+        Country::JO,
+        Country::JP,
+        Country::KE,
+        Country::KG,
+        Country::KH,
+        Country::KI,
+        Country::KM,
+        Country::KN,
+        Country::KP,
+        Country::KR,
+        Country::KW,
+        Country::KY,
+        Country::KZ,
+        Country::LA,
+        Country::LB,
+        Country::LC,
+        Country::LI,
+        Country::LK,
+        Country::LR,
+        Country::LS,
+        Country::LT,
+        Country::LU,
+        Country::LV,
+        Country::LY,
+        Country::MA,
+        Country::MC,
+        Country::MD,
+        Country::ME,
+        Country::MF,
+        Country::MG,
+        Country::MH,
+        Country::MK,
+        Country::ML,
+        Country::MM,
+        Country::MN,
+        Country::MO,
+        Country::MP,
+        Country::MQ,
+        Country::MR,
+        Country::MS,
+        Country::MT,
+        Country::MU,
+        Country::MV,
+        Country::MW,
+        Country::MX,
+        Country::MY,
+        Country::MZ,
+        Country::NA,
+        Country::NC,
+        Country::NE,
+        Country::NF,
+        Country::NG,
+        Country::NI,
+        Country::NL,
+        Country::NO,
+        Country::NP,
+        Country::NR,
+        Country::NU,
+        Country::NZ,
+        Country::OM,
+        Country::PA,
+        Country::PE,
+        Country::PF,
+        Country::PG,
+        Country::PH,
+        Country::PK,
+        Country::PL,
+        Country::PM,
+        Country::PN,
+        Country::PR,
+        Country::PS,
+        Country::PT,
+        Country::PW,
+        Country::PY,
+        Country::QA,
+        Country::RE,
+        Country::RO,
+        Country::RS,
+        Country::RU,
+        Country::RW,
+        Country::SA,
+        Country::SB,
+        Country::SC,
+        Country::SD,
+        Country::SE,
+        Country::SG,
+        Country::SH,
+        Country::SI,
+        Country::SJ,
+        Country::SK,
+        Country::SL,
+        Country::SM,
+        Country::SN,
+        Country::SO,
+        Country::SR,
+        Country::SS,
+        Country::ST,
+        Country::SV,
+        Country::SX,
+        Country::SY,
+        Country::SZ,
+        Country::TC,
+        Country::TD,
+        Country::TF,
+        Country::TG,
+        Country::TH,
+        Country::TJ,
+        Country::TK,
+        Country::TL,
+        Country::TM,
+        Country::TN,
+        Country::TO,
+        Country::TR,
+        Country::TT,
+        Country::TV,
+        Country::TW,
+        Country::TZ,
+        Country::UA,
+        Country::UG,
+        Country::UM,
+        Country::US,
+        Country::UY,
+        Country::UZ,
+        Country::VA,
+        Country::VC,
+        Country::VE,
+        Country::VG,
+        Country::VI,
+        Country::VN,
+        Country::VU,
+        Country::WF,
+        Country::WS,
+        Country::YE,
+        Country::YT,
+        Country::ZA,
+        Country::ZM,
+        Country::ZW,
+    ];
+
+    /// English short name, e.g. `"Brazil"`; `None` for unknown codes
+    pub fn name(&self) -> Option<&'static str> {
+        self.info().map(|(name, _)| name)
+    }
+
+    /// ISO 3166-1 alpha-3 code, e.g. `"BRA"`; `None` for unknown codes
+    pub fn alpha3(&self) -> Option<&'static str> {
+        self.info().map(|(_, alpha3)| alpha3)
+    }
+
+    /// Look a country up by its alpha-3 code, ignoring case
+    pub fn from_alpha3(alpha3: &str) -> Option<Country> {
+        Self::ALL
+            .iter()
+            .find(|country| {
+                country
+                    .alpha3()
+                    .is_some_and(|code| code.eq_ignore_ascii_case(alpha3))
+            })
+            .cloned()
+    }
+
+    /// Rails BlindPay can pay out to for bank accounts in this country.
+    ///
+    /// Countries without a local rail are reached through international
+    /// SWIFT, subject to BlindPay's compliance restrictions.
+    pub fn supported_rails(&self) -> &'static [Rail] {
+        match self {
+            Country::US => US_RAILS,
+            Country::BR => BR_RAILS,
+            Country::MX => MX_RAILS,
+            Country::AR => AR_RAILS,
+            Country::CO => CO_RAILS,
+            Country::Unknown(_) => &[],
+            _ => SWIFT_RAILS,
+        }
+    }
+
+    /// Fiat currency BlindPay delivers to bank accounts in this country;
+    /// SWIFT payouts are sent in US dollars
+    pub fn payout_currency(&self) -> Option<Currency> {
+        match self {
+            Country::US => Some(Currency::USD),
+            Country::BR => Some(Currency::BRL),
+            Country::MX => Some(Currency::MXN),
+            Country::AR => Some(Currency::ARS),
+            Country::CO => Some(Currency::COP),
+            Country::Unknown(_) => None,
+            _ => Some(Currency::USD),
+        }
+    }
+
+    fn info(&self) -> Option<(&'static str, &'static str)> {
+        Some(match self {
+            Country::AD => ("Andorra", "AND"),
+            Country::AE => ("United Arab Emirates", "ARE"),
+            Country::AF => ("Afghanistan", "AFG"),
+            Country::AG => ("Antigua and Barbuda", "ATG"),
+            Country::AI => ("Anguilla", "AIA"),
+            Country::AL => ("Albania", "ALB"),
+            Country::AM => ("Armenia", "ARM"),
+            Country::AO => ("Angola", "AGO"),
+            Country::AQ => ("Antarctica", "ATA"),
+            Country::AR => ("Argentina", "ARG"),
+            Country::AS => ("American Samoa", "ASM"),
+            Country::AT => ("Austria", "AUT"),
+            Country::AU => ("Australia", "AUS"),
+            Country::AW => ("Aruba", "ABW"),
+            Country::AX => ("Åland Islands", "ALA"),
+            Country::AZ => ("Azerbaijan", "AZE"),
+            Country::BA => ("Bosnia and Herzegovina", "BIH"),
+            Country::BB => ("Barbados", "BRB"),
+            Country::BD => ("Bangladesh", "BGD"),
+            Country::BE => ("Belgium", "BEL"),
+            Country::BF => ("Burkina Faso", "BFA"),
+            Country::BG => ("Bulgaria", "BGR"),
+            Country::BH => ("Bahrain", "BHR"),
+            Country::BI => ("Burundi", "BDI"),
+            Country::BJ => ("Benin", "BEN"),
+            Country::BL => ("Saint Barthélemy", "BLM"),
+            Country::BM => ("Bermuda", "BMU"),
+            Country::BN => ("Brunei Darussalam", "BRN"),
+            Country::BO => ("Bolivia", "BOL"),
+            Country::BQ => ("Bonaire, Sint Eustatius and Saba", "BES"),
+            Country::BR => ("Brazil", "BRA"),
+            Country::BS => ("Bahamas", "BHS"),
+            Country::BT => ("Bhutan", "BTN"),
+            Country::BV => ("Bouvet Island", "BVT"),
+            Country::BW => ("Botswana", "BWA"),
+            Country::BY => ("Belarus", "BLR"),
+            Country::BZ => ("Belize", "BLZ"),
+            Country::CA => ("Canada", "CAN"),
+            Country::CC => ("Cocos (Keeling) Islands", "CCK"),
+            Country::CD => ("Congo, The Democratic Republic of the", "COD"),
+            Country::CF => ("Central African Republic", "CAF"),
+            Country::CG => ("Congo", "COG"),
+            Country::CH => ("Switzerland", "CHE"),
+            Country::CI => ("Côte d'Ivoire", "CIV"),
+            Country::CK => ("Cook Islands", "COK"),
+            Country::CL => ("Chile", "CHL"),
+            Country::CM => ("Cameroon", "CMR"),
+            Country::CN => ("China", "CHN"),
+            Country::CO => ("Colombia", "COL"),
+            Country::CR => ("Costa Rica", "CRI"),
+            Country::CU => ("Cuba", "CUB"),
+            Country::CV => ("Cabo Verde", "CPV"),
+            Country::CW => ("Curaçao", "CUW"),
+            Country::CX => ("Christmas Island", "CXR"),
+            Country::CY => ("Cyprus", "CYP"),
+            Country::CZ => ("Czechia", "CZE"),
+            Country::DE => ("Germany", "DEU"),
+            Country::DJ => ("Djibouti", "DJI"),
+            Country::DK => ("Denmark", "DNK"),
+            Country::DM => ("Dominica", "DMA"),
+            Country::DO => ("Dominican Republic", "DOM"),
+            Country::DZ => ("Algeria", "DZA"),
+            Country::EC => ("Ecuador", "ECU"),
+            Country::EE => ("Estonia", "EST"),
+            Country::EG => ("Egypt", "EGY"),
+            Country::EH => ("Western Sahara", "ESH"),
+            Country::ER => ("Eritrea", "ERI"),
+            Country::ES => ("Spain", "ESP"),
+            Country::ET => ("Ethiopia", "ETH"),
+            Country::FI => ("Finland", "FIN"),
+            Country::FJ => ("Fiji", "FJI"),
+            Country::FK => ("Falkland Islands (Malvinas)", "FLK"),
+            Country::FM => ("Micronesia, Federated States of", "FSM"),
+            Country::FO => ("Faroe Islands", "FRO"),
+            Country::FR => ("France", "FRA"),
+            Country::GA => ("Gabon", "GAB"),
+            Country::GB => ("United Kingdom", "GBR"),
+            Country::GD => ("Grenada", "GRD"),
+            Country::GE => ("Georgia", "GEO"),
+            Country::GF => ("French Guiana", "GUF"),
+            Country::GG => ("Guernsey", "GGY"),
+            Country::GH => ("Ghana", "GHA"),
+            Country::GI => ("Gibraltar", "GIB"),
+            Country::GL => ("Greenland", "GRL"),
+            Country::GM => ("Gambia", "GMB"),
+            Country::GN => ("Guinea", "GIN"),
+            Country::GP => ("Guadeloupe", "GLP"),
+            Country::GQ => ("Equatorial Guinea", "GNQ"),
+            Country::GR => ("Greece", "GRC"),
+            Country::GS => ("South Georgia and the South Sandwich Islands", "SGS"),
+            Country::GT => ("Guatemala", "GTM"),
+            Country::GU => ("Guam", "GUM"),
+            Country::GW => ("Guinea-Bissau", "GNB"),
+            Country::GY => ("Guyana", "GUY"),
+            Country::HK => ("Hong Kong", "HKG"),
+            Country::HM => ("Heard Island and McDonald Islands", "HMD"),
+            Country::HN => ("Honduras", "HND"),
+            Country::HR => ("Croatia", "HRV"),
+            Country::HT => ("Haiti", "HTI"),
+            Country::HU => ("Hungary", "HUN"),
+            Country::ID => ("Indonesia", "IDN"),
+            Country::IE => ("Ireland", "IRL"),
+            Country::IL => ("Israel", "ISR"),
+            Country::IM => ("Isle of Man", "IMN"),
+            Country::IN => ("India", "IND"),
+            Country::IO => ("British Indian Ocean Territory", "IOT"),
+            Country::IQ => ("Iraq", "IRQ"),
+            Country::IR => ("Iran", "IRN"),
+            Country::IS => ("Iceland", "ISL"),
+            Country::IT => ("Italy", "ITA"),
+            Country::JE => ("Jersey", "JEY"),
+            Country::JM => ("Jamaica", "JAM"),
+            Country::JO => ("Jordan", "JOR"),
+            Country::JP => ("Japan", "JPN"),
+            Country::KE => ("Kenya", "KEN"),
+            Country::KG => ("Kyrgyzstan", "KGZ"),
+            Country::KH => ("Cambodia", "KHM"),
+            Country::KI => ("Kiribati", "KIR"),
+            Country::KM => ("Comoros", "COM"),
+            Country::KN => ("Saint Kitts and Nevis", "KNA"),
+            Country::KP => ("North Korea", "PRK"),
+            Country::KR => ("South Korea", "KOR"),
+            Country::KW => ("Kuwait", "KWT"),
+            Country::KY => ("Cayman Islands", "CYM"),
+            Country::KZ => ("Kazakhstan", "KAZ"),
+            Country::LA => ("Laos", "LAO"),
+            Country::LB => ("Lebanon", "LBN"),
+            Country::LC => ("Saint Lucia", "LCA"),
+            Country::LI => ("Liechtenstein", "LIE"),
+            Country::LK => ("Sri Lanka", "LKA"),
+            Country::LR => ("Liberia", "LBR"),
+            Country::LS => ("Lesotho", "LSO"),
+            Country::LT => ("Lithuania", "LTU"),
+            Country::LU => ("Luxembourg", "LUX"),
+            Country::LV => ("Latvia", "LVA"),
+            Country::LY => ("Libya", "LBY"),
+            Country::MA => ("Morocco", "MAR"),
+            Country::MC => ("Monaco", "MCO"),
+            Country::MD => ("Moldova", "MDA"),
+            Country::ME => ("Montenegro", "MNE"),
+            Country::MF => ("Saint Martin (French part)", "MAF"),
+            Country::MG => ("Madagascar", "MDG"),
+            Country::MH => ("Marshall Islands", "MHL"),
+            Country::MK => ("North Macedonia", "MKD"),
+            Country::ML => ("Mali", "MLI"),
+            Country::MM => ("Myanmar", "MMR"),
+            Country::MN => ("Mongolia", "MNG"),
+            Country::MO => ("Macao", "MAC"),
+            Country::MP => ("Northern Mariana Islands", "MNP"),
+            Country::MQ => ("Martinique", "MTQ"),
+            Country::MR => ("Mauritania", "MRT"),
+            Country::MS => ("Montserrat", "MSR"),
+            Country::MT => ("Malta", "MLT"),
+            Country::MU => ("Mauritius", "MUS"),
+            Country::MV => ("Maldives", "MDV"),
+            Country::MW => ("Malawi", "MWI"),
+            Country::MX => ("Mexico", "MEX"),
+            Country::MY => ("Malaysia", "MYS"),
+            Country::MZ => ("Mozambique", "MOZ"),
+            Country::NA => ("Namibia", "NAM"),
+            Country::NC => ("New Caledonia", "NCL"),
+            Country::NE => ("Niger", "NER"),
+            Country::NF => ("Norfolk Island", "NFK"),
+            Country::NG => ("Nigeria", "NGA"),
+            Country::NI => ("Nicaragua", "NIC"),
+            Country::NL => ("Netherlands", "NLD"),
+            Country::NO => ("Norway", "NOR"),
+            Country::NP => ("Nepal", "NPL"),
+            Country::NR => ("Nauru", "NRU"),
+            Country::NU => ("Niue", "NIU"),
+            Country::NZ => ("New Zealand", "NZL"),
+            Country::OM => ("Oman", "OMN"),
+            Country::PA => ("Panama", "PAN"),
+            Country::PE => ("Peru", "PER"),
+            Country::PF => ("French Polynesia", "PYF"),
+            Country::PG => ("Papua New Guinea", "PNG"),
+            Country::PH => ("Philippines", "PHL"),
+            Country::PK => ("Pakistan", "PAK"),
+            Country::PL => ("Poland", "POL"),
+            Country::PM => ("Saint Pierre and Miquelon", "SPM"),
+            Country::PN => ("Pitcairn", "PCN"),
+            Country::PR => ("Puerto Rico", "PRI"),
+            Country::PS => ("Palestine, State of", "PSE"),
+            Country::PT => ("Portugal", "PRT"),
+            Country::PW => ("Palau", "PLW"),
+            Country::PY => ("Paraguay", "PRY"),
+            Country::QA => ("Qatar", "QAT"),
+            Country::RE => ("Réunion", "REU"),
+            Country::RO => ("Romania", "ROU"),
+            Country::RS => ("Serbia", "SRB"),
+            Country::RU => ("Russian Federation", "RUS"),
+            Country::RW => ("Rwanda", "RWA"),
+            Country::SA => ("Saudi Arabia", "SAU"),
+            Country::SB => ("Solomon Islands", "SLB"),
+            Country::SC => ("Seychelles", "SYC"),
+            Country::SD => ("Sudan", "SDN"),
+            Country::SE => ("Sweden", "SWE"),
+            Country::SG => ("Singapore", "SGP"),
+            Country::SH => ("Saint Helena, Ascension and Tristan da Cunha", "SHN"),
+            Country::SI => ("Slovenia", "SVN"),
+            Country::SJ => ("Svalbard and Jan Mayen", "SJM"),
+            Country::SK => ("Slovakia", "SVK"),
+            Country::SL => ("Sierra Leone", "SLE"),
+            Country::SM => ("San Marino", "SMR"),
+            Country::SN => ("Senegal", "SEN"),
+            Country::SO => ("Somalia", "SOM"),
+            Country::SR => ("Suriname", "SUR"),
+            Country::SS => ("South Sudan", "SSD"),
+            Country::ST => ("Sao Tome and Principe", "STP"),
+            Country::SV => ("El Salvador", "SLV"),
+            Country::SX => ("Sint Maarten (Dutch part)", "SXM"),
+            Country::SY => ("Syria", "SYR"),
+            Country::SZ => ("Eswatini", "SWZ"),
+            Country::TC => ("Turks and Caicos Islands", "TCA"),
+            Country::TD => ("Chad", "TCD"),
+            Country::TF => ("French Southern Territories", "ATF"),
+            Country::TG => ("Togo", "TGO"),
+            Country::TH => ("Thailand", "THA"),
+            Country::TJ => ("Tajikistan", "TJK"),
+            Country::TK => ("Tokelau", "TKL"),
+            Country::TL => ("Timor-Leste", "TLS"),
+            Country::TM => ("Turkmenistan", "TKM"),
+            Country::TN => ("Tunisia", "TUN"),
+            Country::TO => ("Tonga", "TON"),
+            Country::TR => ("Türkiye", "TUR"),
+            Country::TT => ("Trinidad and Tobago", "TTO"),
+            Country::TV => ("Tuvalu", "TUV"),
+            Country::TW => ("Taiwan", "TWN"),
+            Country::TZ => ("Tanzania", "TZA"),
+            Country::UA => ("Ukraine", "UKR"),
+            Country::UG => ("Uganda", "UGA"),
+            Country::UM => ("United States Minor Outlying Islands", "UMI"),
+            Country::US => ("United States", "USA"),
+            Country::UY => ("Uruguay", "URY"),
+            Country::UZ => ("Uzbekistan", "UZB"),
+            Country::VA => ("Holy See (Vatican City State)", "VAT"),
+            Country::VC => ("Saint Vincent and the Grenadines", "VCT"),
+            Country::VE => ("Venezuela", "VEN"),
+            Country::VG => ("Virgin Islands, British", "VGB"),
+            Country::VI => ("Virgin Islands, U.S.", "VIR"),
+            Country::VN => ("Vietnam", "VNM"),
+            Country::VU => ("Vanuatu", "VUT"),
+            Country::WF => ("Wallis and Futuna", "WLF"),
+            Country::WS => ("Samoa", "WSM"),
+            Country::YE => ("Yemen", "YEM"),
+            Country::YT => ("Mayotte", "MYT"),
+            Country::ZA => ("South Africa", "ZAF"),
+            Country::ZM => ("Zambia", "ZMB"),
+            Country::ZW => ("Zimbabwe", "ZWE"),
+            Country::Unknown(_) => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_country_has_metadata() {
+        assert_eq!(Country::ALL.len(), 249);
+        for country in Country::ALL {
+            assert_eq!(country.as_str().len(), 2);
+            assert_eq!(country.alpha3().unwrap().len(), 3);
+            assert!(country.name().is_some());
+            assert_eq!(Country::from(country.as_str()), *country);
+        }
+    }
+
+    #[test]
+    fn test_country_metadata() {
+        assert_eq!(Country::BR.name(), Some("Brazil"));
+        assert_eq!(Country::BR.alpha3(), Some("BRA"));
+        assert_eq!(Country::from_alpha3("deu"), Some(Country::DE));
+        assert_eq!(Country::MX.supported_rails(), [Rail::SpeiBitso]);
+        assert_eq!(Country::DE.supported_rails(), [Rail::InternationalSwift]);
+        assert_eq!(Country::CO.payout_currency(), Some(Currency::COP));
+
+        let unknown = Country::from("XK");
+        assert!(unknown.is_unknown());
+        assert_eq!(unknown.name(), None);
+        assert!(unknown.supported_rails().is_empty());
+    }
+}
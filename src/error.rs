@@ -1,11 +1,21 @@
+use crate::types::FieldError;
+use reqwest::StatusCode;
+use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, BlindPayError>;
 
 #[derive(Error, Debug)]
 pub enum BlindPayError {
-    #[error("API error: {0}")]
-    ApiError(String),
+    #[error("API error ({}): {}", .0.status, .0.message)]
+    ApiError(Box<ApiError>),
+
+    #[error("Unexpected response ({status}): {body_snippet}")]
+    UnexpectedResponse {
+        status: StatusCode,
+        body_snippet: String,
+    },
 
     #[error("HTTP request failed: {0}")]
     RequestFailed(#[from] reqwest::Error),
@@ -13,6 +23,9 @@ pub enum BlindPayError {
     #[error("JSON serialization/deserialization failed: {0}")]
     SerializationError(#[from] serde_json::Error),
 
+    #[error("Query parameter encoding failed: {0}")]
+    QueryEncodingFailed(#[from] serde_urlencoded::ser::Error),
+
     #[error("Missing API key")]
     MissingApiKey,
 
@@ -21,4 +34,183 @@ pub enum BlindPayError {
 
     #[error("Invalid configuration: {0}")]
     InvalidConfiguration(String),
+
+    #[error("Missing required field: {0}")]
+    MissingField(&'static str),
+
+    #[error("Validation failed: {}", describe_field_errors(.0))]
+    ValidationFailed(Vec<FieldError>),
+
+    #[error("Quote {quote_id} expired before the payout could be submitted")]
+    QuoteExpired { quote_id: String },
+
+    #[error("{}: {source}", describe_unknown_payout(.payout_id.as_deref(), .idempotency_key))]
+    PayoutStatusUnknown {
+        payout_id: Option<String>,
+        idempotency_key: String,
+        #[source]
+        source: Box<BlindPayError>,
+    },
+
+    #[error("Signing failed: {0}")]
+    SigningFailed(#[source] crate::signing::SignerError),
+
+    #[error("Webhook verification failed: {0}")]
+    WebhookVerificationFailed(String),
+
+    #[error("Timed out after {elapsed:?} waiting for {id} to complete")]
+    WaitTimedOut { id: String, elapsed: Duration },
+
+    #[error("Cassette {} could not be read or written: {source}", .path.display())]
+    CassetteFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("No recorded interaction matches {method} {path}")]
+    NoRecordedInteraction { method: String, path: String },
+
+    #[error("The API ignored the {0} filter")]
+    FilterNotApplied(&'static str),
+}
+
+/// Error details returned by the BlindPay API
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// HTTP status of the response
+    pub status: StatusCode,
+    /// Human readable error message
+    pub message: String,
+    /// Machine readable error code, when provided
+    pub code: Option<String>,
+    /// Field-level validation errors, when provided
+    pub errors: Vec<FieldError>,
+    /// Value of the `x-request-id` response header, useful when contacting support
+    pub request_id: Option<String>,
+}
+
+impl ApiError {
+    /// Whether the requested resource does not exist (404)
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND
+    }
+
+    /// Whether the request was rejected by rate limiting (429)
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    /// Whether the request failed validation (400 or 422)
+    pub fn is_validation_error(&self) -> bool {
+        self.status == StatusCode::BAD_REQUEST || self.status == StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    /// Whether the request was rejected for missing or invalid credentials (401 or 403)
+    pub fn is_unauthorized(&self) -> bool {
+        self.status == StatusCode::UNAUTHORIZED || self.status == StatusCode::FORBIDDEN
+    }
+
+    /// Whether the same request may succeed if sent again later
+    pub fn is_retryable(&self) -> bool {
+        is_retryable_status(self.status)
+    }
+}
+
+impl BlindPayError {
+    /// HTTP status of the failed response, if the API was reached
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::ApiError(err) => Some(err.status),
+            Self::UnexpectedResponse { status, .. } => Some(*status),
+            Self::RequestFailed(err) => err.status(),
+            _ => None,
+        }
+    }
+
+    /// Request id of the failed response, if the API returned one
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiError(err) => err.request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Id of a payout that was created before the call failed
+    ///
+    /// When this is set, money may already be moving: look the payout up
+    /// instead of running the call again.
+    pub fn created_payout_id(&self) -> Option<&str> {
+        match self {
+            Self::PayoutStatusUnknown { payout_id, .. } => payout_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Idempotency key of a payout creation whose outcome is unknown
+    ///
+    /// Create the payout again with this key to get the original payout back
+    /// rather than a second one.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        match self {
+            Self::PayoutStatusUnknown {
+                idempotency_key, ..
+            } => Some(idempotency_key),
+            _ => None,
+        }
+    }
+
+    /// Whether the requested resource does not exist (404)
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the request was rejected by rate limiting (429)
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// Whether the same request may succeed if sent again later.
+    ///
+    /// True for timeouts, connection failures, 408, 429 and 5xx responses
+    /// other than 501.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::ApiError(err) => err.is_retryable(),
+            Self::UnexpectedResponse { status, .. } => is_retryable_status(*status),
+            Self::RequestFailed(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err.status().map(is_retryable_status).unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn describe_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("{}: {}", error.field, error.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn describe_unknown_payout(payout_id: Option<&str>, idempotency_key: &str) -> String {
+    match payout_id {
+        Some(payout_id) => format!(
+            "Payout {} was created, but its status could not be fetched",
+            payout_id
+        ),
+        None => format!(
+            "Payout with idempotency key {} may have been created",
+            idempotency_key
+        ),
+    }
+}
+
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || (status.is_server_error() && status != StatusCode::NOT_IMPLEMENTED)
 }
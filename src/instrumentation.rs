@@ -0,0 +1,173 @@
+//! `tracing` spans around API calls, behind the `tracing` feature
+//!
+//! Every call opens a `blindpay.request` span with the method, the path
+//! template, the resource ids taken from the path, the status code, the
+//! attempt number and the latency. Query strings, headers and bodies are never
+//! recorded, so tax ids, account numbers and the API key stay out of traces.
+
+use crate::error::{BlindPayError, Result};
+use crate::middleware::{Middleware, Next};
+use async_trait::async_trait;
+use reqwest::{Method, Request, Response};
+use std::future::Future;
+use std::time::Instant;
+use tracing::field::Empty;
+use tracing::{Instrument, Span};
+
+/// Path segments followed by the id of one of their items
+const COLLECTIONS: &[&str] = &[
+    "instances",
+    "receivers",
+    "bank-accounts",
+    "offramp-wallets",
+    "blockchain-wallets",
+    "virtual-accounts",
+    "payouts",
+    "payins",
+    "partner-fees",
+    "api-keys",
+    "members",
+    "webhook-endpoints",
+    "swift",
+];
+
+/// Path segments that follow a collection without being an id
+const ACTIONS: &[&str] = &["evm", "stellar", "solana", "sign-message", "portal-access"];
+
+/// Split `path` into its template and the ids it contains
+///
+/// The query string is dropped.
+pub(crate) fn path_template(path: &str) -> (String, Vec<&str>) {
+    let path = path.split('?').next().unwrap_or_default();
+    let mut ids = Vec::new();
+    let mut previous = "";
+    let segments: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            let is_id = COLLECTIONS.contains(&previous)
+                && !segment.is_empty()
+                && !ACTIONS.contains(&segment);
+            previous = segment;
+            if is_id {
+                ids.push(segment);
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect();
+    (segments.join("/"), ids)
+}
+
+/// Open the span for one call
+pub(crate) fn request_span(method: &Method, path: &str) -> Span {
+    let (template, ids) = path_template(path);
+    tracing::info_span!(
+        "blindpay.request",
+        method = %method,
+        path = %template,
+        resource_ids = %ids.join(","),
+        status_code = Empty,
+        attempt = Empty,
+        latency_ms = Empty,
+        error = Empty,
+    )
+}
+
+/// Run `call` inside `span`, then record its latency and outcome
+pub(crate) async fn instrument<T>(span: Span, call: impl Future<Output = Result<T>>) -> Result<T> {
+    let started = Instant::now();
+    let result = call.instrument(span.clone()).await;
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    if let Err(err) = &result {
+        span.record("error", error_kind(err));
+    }
+    result
+}
+
+/// Records the attempt number and status code of each try on the call span
+///
+/// Runs after the retry policy, so it sees every attempt.
+pub(crate) struct TraceAttempts;
+
+#[async_trait]
+impl Middleware for TraceAttempts {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response> {
+        let attempt = next.attempt();
+        let started = Instant::now();
+        let result = next.run(request).await;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        let span = Span::current();
+        span.record("attempt", attempt);
+        match &result {
+            Ok(response) => {
+                let status_code = response.status().as_u16();
+                span.record("status_code", status_code);
+                tracing::debug!(attempt, status_code, latency_ms, "attempt finished");
+            }
+            Err(err) => {
+                let error = error_kind(err);
+                tracing::debug!(attempt, latency_ms, error, "attempt failed");
+            }
+        }
+        result
+    }
+}
+
+/// A short name for the kind of `err`
+///
+/// Error messages are left out: transport errors carry the full URL, query
+/// string included, and API errors may echo request fields.
+fn error_kind(err: &BlindPayError) -> &'static str {
+    match err {
+        BlindPayError::ApiError(_) => "api",
+        BlindPayError::UnexpectedResponse { .. } => "unexpected_response",
+        BlindPayError::RequestFailed(err) if err.is_timeout() => "timeout",
+        BlindPayError::RequestFailed(err) if err.is_connect() => "connect",
+        BlindPayError::RequestFailed(_) => "request",
+        BlindPayError::SerializationError(_) => "decode",
+        BlindPayError::NoRecordedInteraction { .. } => "no_recorded_interaction",
+        BlindPayError::CassetteFailed { .. } => "cassette",
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_template_replaces_ids() {
+        let (template, ids) = path_template("/instances/in_123/payouts/pa_456");
+        assert_eq!(template, "/instances/{id}/payouts/{id}");
+        assert_eq!(ids, ["in_123", "pa_456"]);
+
+        let (template, ids) = path_template("/instances/in_1/receivers/re_1/bank-accounts/ba_1");
+        assert_eq!(
+            template,
+            "/instances/{id}/receivers/{id}/bank-accounts/{id}"
+        );
+        assert_eq!(ids, ["in_1", "re_1", "ba_1"]);
+
+        let (template, ids) = path_template("/available/swift/BOFAUS3N");
+        assert_eq!(template, "/available/swift/{id}");
+        assert_eq!(ids, ["BOFAUS3N"]);
+    }
+
+    #[test]
+    fn test_path_template_keeps_actions_and_drops_query() {
+        let (template, ids) = path_template("/instances/in_1/payouts/stellar/authorize");
+        assert_eq!(template, "/instances/{id}/payouts/stellar/authorize");
+        assert_eq!(ids, ["in_1"]);
+
+        let (template, ids) =
+            path_template("/instances/in_1/receivers?email=juan%40example.com&limit=10");
+        assert_eq!(template, "/instances/{id}/receivers");
+        assert_eq!(ids, ["in_1"]);
+
+        let (template, ids) = path_template("/instances/in_1/limits/receivers/re_1");
+        assert_eq!(template, "/instances/{id}/limits/receivers/{id}");
+        assert_eq!(ids, ["in_1", "re_1"]);
+    }
+}
@@ -1,15 +1,37 @@
 pub mod client;
+pub mod country;
 pub mod error;
+#[cfg(feature = "tracing")]
+mod instrumentation;
+mod macros;
+pub mod middleware;
+pub mod money;
+pub mod options;
+pub mod pagination;
+pub mod polling;
+pub mod recording;
 pub mod resources;
+pub mod retry;
+pub mod signing;
+#[cfg(feature = "test-util")]
+pub mod testing;
 pub mod types;
+pub mod validation;
 
-pub use client::BlindPay;
-pub use error::{BlindPayError, Result};
+pub use client::{BlindPay, BlindPayBuilder};
+pub use error::{ApiError, BlindPayError, Result};
+pub use money::{Amount, Money};
+pub use options::RequestOptions;
+pub use resources::webhooks;
+pub use retry::RetryPolicy;
 pub use types::*;
 
 // Re-export commonly used types
 pub mod prelude {
     pub use crate::client::BlindPay;
-    pub use crate::error::{BlindPayError, Result};
+    pub use crate::error::{ApiError, BlindPayError, Result};
+    pub use crate::money::{Amount, Money};
+    pub use crate::options::RequestOptions;
+    pub use crate::retry::RetryPolicy;
     pub use crate::types::*;
 }
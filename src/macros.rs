@@ -0,0 +1,163 @@
+/// Declare a string-valued API enum that tolerates values it does not know.
+///
+/// Each variant is listed with its wire value. An extra `Unknown(String)`
+/// variant keeps any other value received from the API, so a new network,
+/// rail or status added server side does not break deserialization of the
+/// whole response, and serializes back unchanged.
+macro_rules! api_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$variant_meta:meta])* $variant:ident => $value:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $( $(#[$variant_meta])* $variant, )*
+            /// A value this version of the SDK does not know about
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value as sent over the wire
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(value) => value,
+                }
+            }
+
+            /// Whether this is a value this version of the SDK does not know about
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $( $value => $name::$variant, )*
+                    other => $name::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Ok($name::from(value.as_ref()))
+            }
+        }
+    };
+}
+
+pub(crate) use api_enum;
+
+/// Declare a builder for a request input struct.
+///
+/// `required` fields must be set before `build()` succeeds, and `optional`
+/// fields map to `Option` fields of the input and start unset. Every setter
+/// takes `impl Into<T>`, so `&str` works for strings and string-valued enums.
+macro_rules! input_builder {
+    (
+        $(#[$meta:meta])*
+        pub struct $builder:ident => $input:ident {
+            required { $( $required:ident: $required_ty:ty, )* }
+            optional { $( $optional:ident: $optional_ty:ty, )* }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            $( $required: Option<$required_ty>, )*
+            $( $optional: Option<$optional_ty>, )*
+        }
+
+        impl $input {
+            #[doc = concat!("Start a [`", stringify!($builder), "`] with every optional field unset")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                pub fn $required(mut self, $required: impl Into<$required_ty>) -> Self {
+                    self.$required = Some($required.into());
+                    self
+                }
+            )*
+
+            $(
+                pub fn $optional(mut self, $optional: impl Into<$optional_ty>) -> Self {
+                    self.$optional = Some($optional.into());
+                    self
+                }
+            )*
+
+            #[doc = concat!("Build the [`", stringify!($input), "`]")]
+            ///
+            /// Fails with [`BlindPayError::MissingField`](crate::BlindPayError::MissingField)
+            /// naming the first required field that was not set.
+            pub fn build(self) -> crate::error::Result<$input> {
+                Ok($input {
+                    $(
+                        $required: self.$required.ok_or(
+                            crate::error::BlindPayError::MissingField(stringify!($required)),
+                        )?,
+                    )*
+                    $( $optional: self.$optional, )*
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use input_builder;
+
+/// Add `with_options` to a resource holding `client` and `options` fields.
+///
+/// The options are sent with every request made through the returned
+/// handle, so one handle per call is the way to set an idempotency key.
+macro_rules! request_options {
+    ($resource:ident) => {
+        impl $resource {
+            /// Send `options`, such as an idempotency key, with the requests
+            /// made through this handle
+            ///
+            /// See [`RequestOptions`](crate::RequestOptions) for an example.
+            pub fn with_options(mut self, options: crate::options::RequestOptions) -> Self {
+                self.options = options;
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use request_options;
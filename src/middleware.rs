@@ -0,0 +1,148 @@
+//! Hooks run around every HTTP exchange of a [`BlindPay`](crate::BlindPay) client
+//!
+//! A [`Middleware`] receives each request once it is fully built, with
+//! authentication and body in place, and either passes it on with
+//! [`Next::run`] or answers it itself. Register middlewares with
+//! [`BlindPayBuilder::middleware`](crate::BlindPayBuilder::middleware); they
+//! run in registration order, followed by the client's
+//! [`RetryPolicy`](crate::RetryPolicy) and any
+//! [`Recorder`](crate::recording::Recorder).
+//!
+//! [`RetryPolicy`](crate::RetryPolicy), [`AutoIdempotencyKey`] and
+//! [`Recorder`](crate::recording::Recorder) are middlewares themselves, so
+//! they can also be registered at any position of the chain.
+//!
+//! # Example
+//! ```no_run
+//! use async_trait::async_trait;
+//! use blindpay::middleware::{Middleware, Next};
+//! use blindpay::BlindPay;
+//! use reqwest::{Request, Response};
+//! use std::time::Instant;
+//!
+//! struct Timing;
+//!
+//! #[async_trait]
+//! impl Middleware for Timing {
+//!     async fn handle(&self, request: Request, next: Next<'_>) -> blindpay::Result<Response> {
+//!         let (method, path) = (request.method().clone(), request.url().path().to_string());
+//!         let started = Instant::now();
+//!         let response = next.run(request).await;
+//!         println!("{} {} took {:?}", method, path, started.elapsed());
+//!         response
+//!     }
+//! }
+//!
+//! let client = BlindPay::builder()
+//!     .api_key("your-api-key")
+//!     .instance_id("your-instance-id")
+//!     .middleware(Timing)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::error::Result;
+use crate::options::IDEMPOTENCY_KEY_HEADER;
+use async_trait::async_trait;
+use reqwest::header::HeaderValue;
+use reqwest::{Client, Method, Request, Response};
+use std::sync::Arc;
+
+/// A step between the client and the network
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Handle `request`, usually by passing it to `next`
+    ///
+    /// `next` may be cloned to send the request more than once.
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response>;
+}
+
+#[async_trait]
+impl<M: Middleware + ?Sized> Middleware for Arc<M> {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response> {
+        (**self).handle(request, next).await
+    }
+}
+
+/// The rest of the chain after the current middleware
+#[derive(Clone)]
+pub struct Next<'a> {
+    client: &'a Client,
+    middlewares: &'a [Arc<dyn Middleware>],
+    attempt: u32,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(client: &'a Client, middlewares: &'a [Arc<dyn Middleware>]) -> Self {
+        Self {
+            client,
+            middlewares,
+            attempt: 1,
+        }
+    }
+
+    /// Number of the current attempt, starting at 1
+    ///
+    /// Set by the closest enclosing [`RetryPolicy`](crate::RetryPolicy), so
+    /// only middlewares that run after it see retries.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    pub(crate) fn with_attempt(mut self, attempt: u32) -> Self {
+        self.attempt = attempt;
+        self
+    }
+
+    /// Send `request` through the remaining middlewares, then over the network
+    pub async fn run(self, request: Request) -> Result<Response> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                let next = Next {
+                    middlewares: rest,
+                    ..self
+                };
+                middleware.handle(request, next).await
+            }
+            None => Ok(self.client.execute(request).await?),
+        }
+    }
+}
+
+/// Adds a random `Idempotency-Key` header to every `POST` and `PATCH`
+/// request that has none
+///
+/// Registered before the retry policy, the key is generated once per call and
+/// reused by each retry, which makes every create call safe to retry. Keys set
+/// with [`RequestOptions`](crate::RequestOptions) are left untouched.
+///
+/// # Example
+/// ```no_run
+/// use blindpay::middleware::AutoIdempotencyKey;
+/// use blindpay::{BlindPay, RetryPolicy};
+///
+/// let client = BlindPay::builder()
+///     .api_key("your-api-key")
+///     .instance_id("your-instance-id")
+///     .middleware(AutoIdempotencyKey)
+///     .retry_policy(RetryPolicy::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoIdempotencyKey;
+
+#[async_trait]
+impl Middleware for AutoIdempotencyKey {
+    async fn handle(&self, mut request: Request, next: Next<'_>) -> Result<Response> {
+        let mutating = matches!(*request.method(), Method::POST | Method::PATCH);
+        if mutating && !request.headers().contains_key(IDEMPOTENCY_KEY_HEADER) {
+            let key = uuid::Uuid::new_v4().to_string();
+            request.headers_mut().insert(
+                IDEMPOTENCY_KEY_HEADER,
+                HeaderValue::from_str(&key).expect("UUIDs are valid header values"),
+            );
+        }
+        next.run(request).await
+    }
+}
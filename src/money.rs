@@ -0,0 +1,611 @@
+use crate::types::{Currency, StablecoinToken};
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Largest number of decimal places an [`Amount`] keeps
+pub const MAX_SCALE: u32 = 28;
+
+/// An exact decimal amount, used for every monetary value and rate
+///
+/// Amounts are stored as an integer mantissa and a decimal scale, so
+/// `Amount::new(12345, 2)` is exactly `123.45`. Arithmetic never rounds
+/// implicitly; use [`round_dp`](Self::round_dp) when a fixed number of
+/// decimals is needed. The operators panic when the result does not fit;
+/// [`checked_add`](Self::checked_add), [`checked_sub`](Self::checked_sub),
+/// [`checked_mul`](Self::checked_mul) and [`checked_neg`](Self::checked_neg)
+/// return `None` instead.
+///
+/// The API sends amounts as JSON numbers. serde_json reads and writes them
+/// as their exact decimal text, so no digit is lost to `f64`. Values that
+/// were first buffered into a `serde_json::Value` or another format only keep
+/// `f64` precision. Strings such as `"123.45"` are accepted as well.
+///
+/// # Example
+/// ```
+/// use blindpay::Amount;
+///
+/// let price: Amount = "19.99".parse().unwrap();
+/// let total = price * Amount::from(3);
+/// assert_eq!(total.to_string(), "59.97");
+/// assert_eq!(Amount::new(1, 1) + Amount::new(2, 1), "0.3".parse().unwrap());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Amount {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Amount {
+    pub const ZERO: Amount = Amount {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// `mantissa / 10^scale`
+    ///
+    /// # Panics
+    /// If `scale` is greater than [`MAX_SCALE`].
+    pub fn new(mantissa: i128, scale: u32) -> Self {
+        assert!(scale <= MAX_SCALE, "scale {} exceeds {}", scale, MAX_SCALE);
+        Self { mantissa, scale }.normalized()
+    }
+
+    /// The integer digits of the amount, without the decimal point
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of digits after the decimal point
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Convert a float through its shortest round-trip representation,
+    /// so `0.1` becomes exactly `0.1`. Returns `None` for NaN and infinities.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        value.to_string().parse().ok()
+    }
+
+    /// Nearest `f64`; may lose precision
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    /// `self + other`, or `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (left, right, scale) = self.aligned(other)?;
+        Some(Self::new(left.checked_add(right)?, scale))
+    }
+
+    /// `self - other`, or `None` on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (left, right, scale) = self.aligned(other)?;
+        Some(Self::new(left.checked_sub(right)?, scale))
+    }
+
+    /// `-self`, or `None` on overflow
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            mantissa: self.mantissa.checked_neg()?,
+            scale: self.scale,
+        })
+    }
+
+    /// `self * other`, or `None` on overflow or when the product needs more
+    /// than [`MAX_SCALE`] decimal places
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let scale = self.scale + other.scale;
+        if scale > MAX_SCALE {
+            return None;
+        }
+        Some(Self::new(self.mantissa.checked_mul(other.mantissa)?, scale))
+    }
+
+    /// Round to `dp` decimal places, halves away from zero
+    pub fn round_dp(&self, dp: u32) -> Self {
+        if self.scale <= dp {
+            return *self;
+        }
+        let divisor = 10i128.pow(self.scale - dp);
+        let quotient = self.mantissa / divisor;
+        let remainder = (self.mantissa % divisor).abs();
+        let rounded = if remainder * 2 >= divisor {
+            quotient + self.mantissa.signum()
+        } else {
+            quotient
+        };
+        Self::new(rounded, dp)
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.scale > 0 && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        if self.mantissa == 0 {
+            self.scale = 0;
+        }
+        self
+    }
+
+    /// Both mantissas brought to the larger of the two scales, or `None`
+    /// when the one with fewer decimals overflows
+    fn aligned(self, other: Self) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        Some((
+            self.mantissa.checked_mul(10i128.pow(scale - self.scale))?,
+            other
+                .mantissa
+                .checked_mul(10i128.pow(scale - other.scale))?,
+            scale,
+        ))
+    }
+
+    /// Parse a JSON number, which may carry an exponent
+    fn from_json_number(text: &str) -> Option<Self> {
+        let (digits, exponent) = match text.split_once(['e', 'E']) {
+            Some((digits, exponent)) => (digits, exponent.parse::<i32>().ok()?),
+            None => (text, 0),
+        };
+        let amount: Amount = digits.parse().ok()?;
+        if amount.is_zero() {
+            return Some(Self::ZERO);
+        }
+        let scale = i64::from(amount.scale) - i64::from(exponent);
+        if scale < 0 {
+            let factor = 10i128.checked_pow(u32::try_from(-scale).ok()?)?;
+            return Some(Self::new(amount.mantissa.checked_mul(factor)?, 0));
+        }
+        // Trailing zeros do not count against the scale limit
+        let scale = u32::try_from(scale).ok()?;
+        let amount = Self {
+            mantissa: amount.mantissa,
+            scale,
+        }
+        .normalized();
+        (amount.scale <= MAX_SCALE).then_some(amount)
+    }
+}
+
+impl Default for Amount {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for Amount {
+    fn eq(&self, other: &Self) -> bool {
+        // Amounts are always normalized, so equal values have equal parts
+        self.mantissa == other.mantissa && self.scale == other.scale
+    }
+}
+
+impl Eq for Amount {}
+
+impl Hash for Amount {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mantissa.hash(state);
+        self.scale.hash(state);
+    }
+}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Amount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.aligned(*other) {
+            Some((left, right, _)) => left.cmp(&right),
+            // Only the operand with fewer decimals is scaled up, so it is the
+            // one that overflowed and has the larger magnitude
+            None if self.scale < other.scale => {
+                if self.is_negative() {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+            None => {
+                if other.is_negative() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            }
+        }
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    /// # Panics
+    /// On overflow; see [`Amount::checked_add`].
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("amount overflow in addition")
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    /// # Panics
+    /// On overflow; see [`Amount::checked_sub`].
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("amount overflow in subtraction")
+    }
+}
+
+impl Mul for Amount {
+    type Output = Amount;
+
+    /// Exact product.
+    ///
+    /// # Panics
+    /// On overflow, or if the result needs more than [`MAX_SCALE`] decimal
+    /// places; round the operands first, or use [`Amount::checked_mul`].
+    fn mul(self, other: Self) -> Self {
+        let scale = self.scale + other.scale;
+        assert!(scale <= MAX_SCALE, "scale {} exceeds {}", scale, MAX_SCALE);
+        self.checked_mul(other)
+            .expect("amount overflow in multiplication")
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    /// # Panics
+    /// On overflow; see [`Amount::checked_neg`].
+    fn neg(self) -> Self {
+        self.checked_neg().expect("amount overflow in negation")
+    }
+}
+
+macro_rules! amount_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Amount {
+                fn from(value: $int) -> Self {
+                    Self::new(value.into(), 0)
+                }
+            }
+        )*
+    };
+}
+
+amount_from_int!(i32, i64, u32, u64);
+
+impl fmt::Debug for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, int, frac)
+    }
+}
+
+/// Error returned when parsing an [`Amount`] from a string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAmountError(String);
+
+impl fmt::Display for ParseAmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid amount: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseAmountError {}
+
+impl FromStr for Amount {
+    type Err = ParseAmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseAmountError(s.to_string());
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if (int.is_empty() && frac.is_empty()) || !all_digits(int) || !all_digits(frac) {
+            return Err(error());
+        }
+
+        // Trailing zeros carry no value and would only eat into the scale
+        let frac = frac.trim_end_matches('0');
+        if frac.len() > MAX_SCALE as usize {
+            return Err(error());
+        }
+        let mut mantissa: i128 = 0;
+        for digit in int.bytes().chain(frac.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(digit - b'0')))
+                .ok_or_else(error)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        Ok(Self::new(mantissa, frac.len() as u32))
+    }
+}
+
+/// Name serde_json gives the newtype struct it fills with the raw JSON text
+/// of a value (its `raw_value` feature)
+const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
+
+/// Key of the map serde_json hands over for a number when a dependent crate
+/// enables its `arbitrary_precision` feature
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.scale == 0 {
+            if let Ok(value) = i64::try_from(self.mantissa) {
+                return serializer.serialize_i64(value);
+            }
+        }
+        // serde_json writes floats in their shortest round-trip form, so an
+        // amount that survives the trip comes out as written
+        let float = self.to_f64();
+        if Amount::from_f64(float) == Some(*self) {
+            return serializer.serialize_f64(float);
+        }
+        match serde_json::value::RawValue::from_string(self.to_string()) {
+            Ok(raw) => raw.serialize(serializer),
+            Err(_) => serializer.serialize_f64(float),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl<'de> Visitor<'de> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a decimal number or numeric string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Amount, E> {
+                Ok(Amount::from(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Amount, E> {
+                Ok(Amount::from(value))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Amount, E> {
+                Amount::from_f64(value)
+                    .ok_or_else(|| E::custom(format!("invalid amount: {}", value)))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Amount, E> {
+                value.trim().parse().map_err(E::custom)
+            }
+
+            // Deserializers other than serde_json's ignore the raw value
+            // request and hand over the value itself
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Amount, D::Error> {
+                deserializer.deserialize_any(self)
+            }
+
+            // serde_json hands over the exact text of the value this way
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Amount, A::Error> {
+                let key: String = map
+                    .next_key()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                if key != RAW_VALUE_TOKEN && key != NUMBER_TOKEN {
+                    return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+                }
+                let text: String = map.next_value()?;
+                let text = text.trim();
+                let amount = if text.starts_with('"') {
+                    serde_json::from_str::<String>(text)
+                        .ok()
+                        .and_then(|value| value.trim().parse().ok())
+                } else {
+                    Amount::from_json_number(text)
+                };
+                amount.ok_or_else(|| de::Error::custom(format!("invalid amount: {}", text)))
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(RAW_VALUE_TOKEN, AmountVisitor)
+    }
+}
+
+/// An [`Amount`] together with the currency it is denominated in
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Money {
+    pub amount: Amount,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: Amount, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+impl From<StablecoinToken> for Currency {
+    fn from(token: StablecoinToken) -> Self {
+        Currency::from(token.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(s: &str) -> Amount {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        for s in [
+            "0",
+            "1",
+            "-1",
+            "0.1",
+            "123.45",
+            "-0.005",
+            "1000000000000.01",
+        ] {
+            assert_eq!(amount(s).to_string(), s);
+        }
+        assert_eq!(amount("1.2300").to_string(), "1.23");
+        assert_eq!(amount("+.5").to_string(), "0.5");
+        assert!("".parse::<Amount>().is_err());
+        assert!("1.2.3".parse::<Amount>().is_err());
+        assert!("1e5".parse::<Amount>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic_is_exact() {
+        assert_eq!(amount("0.1") + amount("0.2"), amount("0.3"));
+        assert_eq!(amount("10") - amount("0.01"), amount("9.99"));
+        assert_eq!(amount("19.99") * amount("3"), amount("59.97"));
+        assert_eq!(-amount("1.5"), amount("-1.5"));
+        assert!(amount("1.10") == amount("1.1"));
+        assert!(amount("2") > amount("1.999"));
+    }
+
+    #[test]
+    fn test_round_dp() {
+        assert_eq!(amount("1.005").round_dp(2), amount("1.01"));
+        assert_eq!(amount("-1.005").round_dp(2), amount("-1.01"));
+        assert_eq!(amount("1.004").round_dp(2), amount("1"));
+        assert_eq!(amount("1.5").round_dp(3), amount("1.5"));
+    }
+
+    #[test]
+    fn test_json_round_trip_is_lossless() {
+        let parsed: Vec<Amount> =
+            serde_json::from_str(r#"[0.1, 100.10, 5.4321, 1000, "12.345678901234567890"]"#)
+                .unwrap();
+        assert_eq!(parsed[0], amount("0.1"));
+        assert_eq!(parsed[1], amount("100.1"));
+        assert_eq!(parsed[4].to_string(), "12.34567890123456789");
+        assert_eq!(
+            serde_json::to_string(&parsed[..4]).unwrap(),
+            "[0.1,100.1,5.4321,1000]"
+        );
+
+        let json = "[123456789012345.678901234567,1.5e3,25E-4]";
+        let precise: Vec<Amount> = serde_json::from_str(json).unwrap();
+        assert_eq!(precise[0], amount("123456789012345.678901234567"));
+        assert_eq!(precise[1], amount("1500"));
+        assert_eq!(precise[2], amount("0.0025"));
+        assert_eq!(
+            serde_json::to_string(&precise).unwrap(),
+            "[123456789012345.678901234567,1500,0.0025]"
+        );
+
+        #[derive(Deserialize)]
+        struct Envelope {
+            data: Amount,
+            quoted: Amount,
+        }
+        let envelope: Envelope =
+            serde_json::from_str(r#"{"data":0.10000000000000000001,"quoted":"1.50"}"#).unwrap();
+        assert_eq!(envelope.data, amount("0.10000000000000000001"));
+        assert_eq!(envelope.quoted, amount("1.5"));
+
+        // Buffered values keep f64 precision rather than failing
+        let value = serde_json::json!({"data": 0.25, "quoted": 3});
+        let envelope: Envelope = serde_json::from_value(value).unwrap();
+        assert_eq!(envelope.data, amount("0.25"));
+        assert_eq!(envelope.quoted, amount("3"));
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Either {
+            Amount { amount: Amount },
+        }
+        let Either::Amount { amount: buffered } =
+            serde_json::from_str(r#"{"amount":12.5}"#).unwrap();
+        assert_eq!(buffered, amount("12.5"));
+    }
+
+    #[test]
+    fn test_overflow_is_checked() {
+        let max = Amount::new(i128::MAX, 0);
+        assert_eq!(max.checked_add(Amount::from(1)), None);
+        assert_eq!(max.checked_sub(-Amount::from(1)), None);
+        assert_eq!(max.checked_mul(Amount::from(2)), None);
+        assert_eq!(max.checked_add(Amount::new(1, 1)), None);
+        assert_eq!(Amount::new(1, 20).checked_mul(Amount::new(1, 20)), None);
+        assert_eq!(
+            Amount::from(2).checked_mul(amount("1.5")),
+            Some(Amount::from(3))
+        );
+        assert_eq!(Amount::new(i128::MIN, 0).checked_neg(), None);
+        assert_eq!(max.checked_neg(), Some(Amount::new(-i128::MAX, 0)));
+        assert!(max > Amount::new(1, 1));
+        assert!(-max < Amount::new(-1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "amount overflow")]
+    fn test_add_panics_on_overflow() {
+        let _ = Amount::new(i128::MAX, 0) + Amount::from(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "amount overflow in negation")]
+    fn test_neg_panics_on_overflow() {
+        let _ = -Amount::new(i128::MIN, 0);
+    }
+
+    #[test]
+    fn test_money_display() {
+        let money = Money::new(amount("12.5"), StablecoinToken::USDC.into());
+        assert_eq!(money.to_string(), "12.5 USDC");
+    }
+}
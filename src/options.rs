@@ -0,0 +1,59 @@
+/// Header carrying the idempotency key of a request
+pub(crate) const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Per-request options, set on a resource handle with `with_options`
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::{BlindPay, RequestOptions};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let payout = client
+///     .payouts()
+///     .with_options(RequestOptions::new().idempotency_key("order-42-payout"))
+///     .create_evm("qu_123", "0xDD6a3aD0949396e57C7738ba8FC1A46A5a1C372C")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    idempotency_key: Option<IdempotencyKey>,
+}
+
+#[derive(Debug, Clone)]
+enum IdempotencyKey {
+    Fixed(String),
+    Generated,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send the given `Idempotency-Key` header with the request
+    ///
+    /// Sending the same key again returns the original result instead of
+    /// performing the operation twice.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(IdempotencyKey::Fixed(key.into()));
+        self
+    }
+
+    /// Send a randomly generated `Idempotency-Key` header with the request
+    ///
+    /// The key is generated once per call and reused by every retry of that
+    /// call, which makes `POST` requests eligible for automatic retries.
+    pub fn auto_idempotency_key(mut self) -> Self {
+        self.idempotency_key = Some(IdempotencyKey::Generated);
+        self
+    }
+
+    pub(crate) fn resolve_idempotency_key(&self) -> Option<String> {
+        self.idempotency_key.as_ref().map(|key| match key {
+            IdempotencyKey::Fixed(key) => key.clone(),
+            IdempotencyKey::Generated => uuid::Uuid::new_v4().to_string(),
+        })
+    }
+}
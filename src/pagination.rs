@@ -0,0 +1,196 @@
+use crate::error::Result;
+use crate::types::PaginationParams;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+
+/// Controls how list streams walk through pages
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::pagination::PaginateOptions;
+/// # use futures_util::TryStreamExt;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let options = PaginateOptions::new().page_size(100).max_items(1_000);
+/// let mut payouts = Box::pin(client.payouts().list_stream(None, options));
+/// while let Some(payout) = payouts.try_next().await? {
+///     println!("{} {:?}", payout.id, payout.status);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PaginateOptions {
+    page_size: Option<u32>,
+    max_items: Option<usize>,
+}
+
+impl PaginateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of items requested per page
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Stop after yielding this many items in total
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+/// One page of results and the cursor of the page after it
+pub(crate) struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// A page followed by another one when `has_more` is set, using the
+    /// id of its last item as the `starting_after` cursor
+    pub fn with_cursor(items: Vec<T>, has_more: bool, id: impl Fn(&T) -> &str) -> Self {
+        let next_cursor = if has_more {
+            items.last().map(|item| id(item).to_string())
+        } else {
+            None
+        };
+        Self { items, next_cursor }
+    }
+
+    /// The only page of an endpoint that returns everything at once
+    pub fn single(items: Vec<T>) -> Self {
+        Self {
+            items,
+            next_cursor: None,
+        }
+    }
+}
+
+/// List parameters that carry cursor pagination alongside their filters
+pub(crate) trait Paginated: Clone {
+    fn pagination_mut(&mut self) -> &mut PaginationParams;
+}
+
+impl Paginated for PaginationParams {
+    fn pagination_mut(&mut self) -> &mut PaginationParams {
+        self
+    }
+}
+
+/// Stream every item of a list endpoint, fetching pages on demand.
+///
+/// Filters in `params` are sent unchanged with every page; only the cursor
+/// fields move forward.
+pub(crate) fn paginate<T, P, F, Fut>(
+    mut params: P,
+    options: PaginateOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<T>>
+where
+    P: Paginated,
+    F: FnMut(P) -> Fut,
+    Fut: Future<Output = Result<Page<T>>>,
+{
+    if let Some(page_size) = options.page_size {
+        params.pagination_mut().limit = Some(page_size);
+    }
+
+    let pages = stream::try_unfold((fetch, Some(params)), |(mut fetch, params)| async move {
+        let Some(params) = params else {
+            return Ok(None);
+        };
+        let page = match fetch(params.clone()).await {
+            Ok(page) => page,
+            Err(err) => return Err(err),
+        };
+        let next_params = page.next_cursor.map(|cursor| {
+            let mut params = params;
+            let pagination = params.pagination_mut();
+            pagination.offset = None;
+            pagination.starting_after = Some(cursor);
+            pagination.ending_before = None;
+            params
+        });
+        Ok(Some((page.items, (fetch, next_params))))
+    });
+
+    pages
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .take(options.max_items.unwrap_or(usize::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursors() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let items: Vec<String> = paginate(
+            PaginationParams::default(),
+            PaginateOptions::new().page_size(2),
+            move |p| {
+                seen.lock().unwrap().push(p.clone());
+                async move {
+                    let items = match p.starting_after.as_deref() {
+                        None => vec!["a".to_string(), "b".to_string()],
+                        Some("b") => vec!["c".to_string(), "d".to_string()],
+                        _ => vec!["e".to_string()],
+                    };
+                    let has_more = items.len() == 2;
+                    Ok(Page::with_cursor(items, has_more, |item| item.as_str()))
+                }
+            },
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, ["a", "b", "c", "d", "e"]);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|p| p.limit == Some(2)));
+        assert_eq!(requests[2].starting_after.as_deref(), Some("d"));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_at_max_items() {
+        let mut calls = 0;
+        let items: Vec<u32> = paginate(
+            PaginationParams::default(),
+            PaginateOptions::new().max_items(3),
+            |_| {
+                calls += 1;
+                async { Ok(Page::with_cursor(vec![1, 2], true, |_| "cursor")) }
+            },
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, [1, 2, 1]);
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error() {
+        let results: Vec<Result<u32>> = paginate(
+            PaginationParams::default(),
+            PaginateOptions::new(),
+            |_| async { Err(crate::error::BlindPayError::MissingApiKey) },
+        )
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+}
@@ -0,0 +1,390 @@
+use crate::error::{BlindPayError, Result};
+use crate::types::{TrackingStatus, TransactionStatus};
+use futures_util::stream::{self, Stream};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Controls how [`wait_for_completion`](crate::resources::payouts::PayoutsResource::wait_for_completion)
+/// and `watch` poll a payout or payin
+///
+/// The delay between polls starts at `poll_interval`, doubles after every
+/// poll that saw no change (up to `max_interval`) and resets as soon as the
+/// tracking state moves.
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::polling::WaitOptions;
+/// # use std::time::Duration;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let options = WaitOptions::new()
+///     .poll_interval(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30 * 60));
+/// let payout = client.payouts().wait_for_completion("pa_123", options).await?;
+/// println!("{:?}", payout.status);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WaitOptions {
+    poll_interval: Duration,
+    max_interval: Duration,
+    timeout: Duration,
+}
+
+impl Default for WaitOptions {
+    /// Poll every 2 seconds, backing off to 30 seconds, for up to 10 minutes
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+impl WaitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delay before the second poll, and after every observed change
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Upper bound for the delay between two polls
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Give up with [`BlindPayError::WaitTimedOut`] after this long
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// One of the tracking sections of a payout or payin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrackingStage {
+    /// `tracking_transaction`
+    Transaction,
+    /// `tracking_payment`
+    Payment,
+    /// `tracking_liquidity` (payouts only)
+    Liquidity,
+    /// `tracking_complete`
+    Complete,
+    /// `tracking_partner_fee`
+    PartnerFee,
+}
+
+/// A new state of a polled payout or payin
+#[derive(Debug, Clone)]
+pub struct TrackingUpdate<T> {
+    /// Stages whose step differs from the previous update; every stage on
+    /// the first update
+    pub changed: Vec<TrackingStage>,
+    /// The payout or payin as last fetched
+    pub current: T,
+}
+
+/// Resources whose progress can be polled
+pub(crate) trait Trackable {
+    fn status(&self) -> &TransactionStatus;
+    fn tracking_steps(&self) -> Vec<(TrackingStage, TrackingStatus)>;
+}
+
+struct WatchState<F> {
+    fetch: F,
+    id: String,
+    options: WaitOptions,
+    started: Instant,
+    delay: Duration,
+    last: Option<(TransactionStatus, Vec<(TrackingStage, TrackingStatus)>)>,
+    done: bool,
+}
+
+/// Poll `fetch` until the resource reaches a terminal status, yielding an
+/// update whenever its status or a tracking step changes
+pub(crate) fn watch<T, F, Fut>(
+    id: String,
+    options: WaitOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<TrackingUpdate<T>>>
+where
+    T: Trackable,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let state = WatchState {
+        fetch,
+        id,
+        delay: options.poll_interval,
+        options,
+        started: Instant::now(),
+        last: None,
+        done: false,
+    };
+
+    stream::try_unfold(state, |mut state| async move {
+        if state.done {
+            return Ok(None);
+        }
+
+        loop {
+            // The first poll goes out at once, every later one after a delay
+            if state.last.is_some() {
+                if state.started.elapsed() + state.delay > state.options.timeout {
+                    return Err(timed_out(state));
+                }
+                tokio::time::sleep(state.delay).await;
+            }
+
+            let remaining = state
+                .options
+                .timeout
+                .saturating_sub(state.started.elapsed());
+            let current = match tokio::time::timeout(remaining, (state.fetch)()).await {
+                Ok(current) => current?,
+                Err(_) => return Err(timed_out(state)),
+            };
+            let status = current.status().clone();
+            let steps = current.tracking_steps();
+
+            let changed: Vec<TrackingStage> = match &state.last {
+                None => steps.iter().map(|(stage, _)| *stage).collect(),
+                Some((_, last_steps)) => steps
+                    .iter()
+                    .filter(|step| !last_steps.contains(step))
+                    .map(|(stage, _)| *stage)
+                    .collect(),
+            };
+            let status_changed = state
+                .last
+                .as_ref()
+                .map_or(true, |(last_status, _)| *last_status != status);
+
+            state.done = status.is_terminal();
+            if status_changed || !changed.is_empty() {
+                state.delay = state.options.poll_interval;
+                state.last = Some((status, steps));
+                return Ok(Some((TrackingUpdate { changed, current }, state)));
+            }
+            state.delay = state
+                .delay
+                .saturating_mul(2)
+                .min(state.options.max_interval);
+        }
+    })
+}
+
+fn timed_out<F>(state: WatchState<F>) -> BlindPayError {
+    BlindPayError::WaitTimedOut {
+        id: state.id,
+        elapsed: state.started.elapsed(),
+    }
+}
+
+/// Drive [`watch`] to the end and return the resource in its terminal state
+pub(crate) async fn wait_for_completion<T, F, Fut>(
+    id: String,
+    options: WaitOptions,
+    fetch: F,
+) -> Result<T>
+where
+    T: Trackable,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    use futures_util::TryStreamExt;
+
+    let mut updates = Box::pin(watch(id, options, fetch));
+    let mut last = None;
+    while let Some(update) = updates.try_next().await? {
+        last = Some(update.current);
+    }
+    Ok(last.expect("watch yields at least one update before ending"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::TryStreamExt;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    struct Fake {
+        status: TransactionStatus,
+        payment: TrackingStatus,
+    }
+
+    impl Trackable for Fake {
+        fn status(&self) -> &TransactionStatus {
+            &self.status
+        }
+
+        fn tracking_steps(&self) -> Vec<(TrackingStage, TrackingStatus)> {
+            vec![
+                (TrackingStage::Transaction, TrackingStatus::Completed),
+                (TrackingStage::Payment, self.payment.clone()),
+            ]
+        }
+    }
+
+    fn fast() -> WaitOptions {
+        WaitOptions::new()
+            .poll_interval(Duration::from_millis(1))
+            .max_interval(Duration::from_millis(4))
+    }
+
+    fn scripted(states: Vec<Fake>) -> impl FnMut() -> std::future::Ready<Result<Fake>> {
+        let states = Arc::new(Mutex::new(states.into_iter()));
+        move || {
+            let mut states = states.lock().unwrap();
+            let next = states.next().expect("polled past the end of the script");
+            std::future::ready(Ok(next))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch_yields_only_changes_until_terminal() {
+        let processing = Fake {
+            status: TransactionStatus::Processing,
+            payment: TrackingStatus::Processing,
+        };
+        let paid = Fake {
+            status: TransactionStatus::Processing,
+            payment: TrackingStatus::Completed,
+        };
+        let completed = Fake {
+            status: TransactionStatus::Completed,
+            payment: TrackingStatus::Completed,
+        };
+        let fetch = scripted(vec![
+            processing.clone(),
+            processing,
+            paid.clone(),
+            paid,
+            completed,
+        ]);
+
+        let updates: Vec<_> = watch("pa_1".to_string(), fast(), fetch)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(updates.len(), 3);
+        assert_eq!(
+            updates[0].changed,
+            [TrackingStage::Transaction, TrackingStage::Payment]
+        );
+        assert_eq!(updates[1].changed, [TrackingStage::Payment]);
+        assert!(updates[2].changed.is_empty());
+        assert_eq!(updates[2].current.status, TransactionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_returns_terminal_state() {
+        let fetch = scripted(vec![
+            Fake {
+                status: TransactionStatus::OnHold,
+                payment: TrackingStatus::OnHold,
+            },
+            Fake {
+                status: TransactionStatus::Failed,
+                payment: TrackingStatus::OnHold,
+            },
+        ]);
+
+        let result = wait_for_completion("pa_1".to_string(), fast(), fetch)
+            .await
+            .unwrap();
+        assert_eq!(result.status, TransactionStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_times_out() {
+        let options = fast().timeout(Duration::from_millis(20));
+        let fetch = || {
+            std::future::ready(Ok(Fake {
+                status: TransactionStatus::Processing,
+                payment: TrackingStatus::Processing,
+            }))
+        };
+
+        let err = wait_for_completion("pa_1".to_string(), options, fetch)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BlindPayError::WaitTimedOut { ref id, .. } if id == "pa_1"));
+    }
+
+    #[tokio::test]
+    async fn test_watch_waits_after_a_change() {
+        let polls = Arc::new(Mutex::new(Vec::new()));
+        let mut fetch = scripted(vec![
+            Fake {
+                status: TransactionStatus::Processing,
+                payment: TrackingStatus::Processing,
+            },
+            Fake {
+                status: TransactionStatus::Completed,
+                payment: TrackingStatus::Completed,
+            },
+        ]);
+        let recorded = polls.clone();
+        let fetch = move || {
+            recorded.lock().unwrap().push(Instant::now());
+            fetch()
+        };
+
+        let options = fast().poll_interval(Duration::from_millis(30));
+        let updates: Vec<_> = watch("pa_1".to_string(), options, fetch)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(updates.len(), 2);
+        let polls = polls.lock().unwrap();
+        assert!(polls[1] - polls[0] >= Duration::from_millis(30));
+    }
+
+    #[tokio::test]
+    async fn test_watch_times_out_while_status_keeps_changing() {
+        let mut flip = false;
+        let fetch = move || {
+            flip = !flip;
+            let payment = if flip {
+                TrackingStatus::Processing
+            } else {
+                TrackingStatus::OnHold
+            };
+            std::future::ready(Ok(Fake {
+                status: TransactionStatus::Processing,
+                payment,
+            }))
+        };
+
+        let options = fast().timeout(Duration::from_millis(20));
+        let err = wait_for_completion("pa_1".to_string(), options, fetch)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BlindPayError::WaitTimedOut { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_watch_times_out_on_a_hung_fetch() {
+        let options = fast().timeout(Duration::from_millis(20));
+        let fetch = std::future::pending::<Result<Fake>>;
+
+        let err = wait_for_completion("pa_1".to_string(), options, fetch)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BlindPayError::WaitTimedOut { .. }));
+    }
+}
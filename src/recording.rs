@@ -0,0 +1,568 @@
+//! Record the HTTP exchanges of a client to a cassette file and replay them
+//! later without network access
+//!
+//! A [`Recorder`] registered with
+//! [`BlindPayBuilder::recorder`](crate::BlindPayBuilder::recorder) sits
+//! between the client and the network. In record mode every request and its
+//! response are appended to a JSON cassette; in replay mode responses are
+//! served from that cassette and nothing is sent.
+//!
+//! The API key never reaches the cassette, and personal data such as tax ids,
+//! names, emails and account numbers is replaced by [`REDACTED`] in query
+//! strings and in request and response bodies, as are webhook signing
+//! secrets. Add fields with [`Recorder::redact_field`].
+//!
+//! # Example
+//! ```no_run
+//! use blindpay::recording::{MatchOn, Recorder};
+//! use blindpay::BlindPay;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Capture the exchanges of a debugging session
+//! let client = BlindPay::builder()
+//!     .api_key("your-api-key")
+//!     .instance_id("your-instance-id")
+//!     .recorder(Recorder::record("cassettes/payout.json"))
+//!     .build()?;
+//! client.payouts().get("pa_123").await?;
+//!
+//! // Later, in a test
+//! let recorder =
+//!     Recorder::replay("cassettes/payout.json")?.match_on([MatchOn::Method, MatchOn::Path]);
+//! let client = BlindPay::builder()
+//!     .api_key("test-api-key")
+//!     .instance_id("your-instance-id")
+//!     .recorder(recorder)
+//!     .build()?;
+//! let payout = client.payouts().get("pa_123").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{BlindPayError, Result};
+use crate::middleware::{Middleware, Next};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Placeholder written in place of redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Body fields redacted by default, wherever they appear
+const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "tax_id",
+    "email",
+    "phone_number",
+    "first_name",
+    "last_name",
+    "legal_name",
+    "date_of_birth",
+    "address_line_1",
+    "address_line_2",
+    "postal_code",
+    "ip_address",
+    "id_doc_front_file",
+    "id_doc_back_file",
+    "proof_of_address_doc_file",
+    "beneficiary_name",
+    "account_number",
+    "routing_number",
+    "pix_key",
+    "spei_clabe",
+    "transfers_account",
+    "ach_cop_beneficiary_first_name",
+    "ach_cop_beneficiary_last_name",
+    "ach_cop_document_id",
+    "ach_cop_email",
+    "ach_cop_bank_account",
+    "swift_account_holder_name",
+    "swift_account_number_iban",
+    "recipient_name",
+    "recipient_tax_id",
+    "recipient_account_number",
+];
+
+/// Field holding the signing secret in webhook endpoint secret responses
+const WEBHOOK_SECRET_FIELD: &str = "key";
+
+/// Headers whose value is never written to a cassette
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// Headers describing the raw body, which no longer hold once it is redacted
+const BODY_FRAMING_HEADERS: &[&str] = &["content-length", "transfer-encoding"];
+
+/// Parts of a request compared when looking for its recorded response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchOn {
+    /// The HTTP method
+    Method,
+    /// The URL path and query string
+    Path,
+    /// The JSON body, after redaction
+    Body,
+}
+
+/// Recorded exchanges, as stored in a cassette file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Read a cassette from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read(path).map_err(|source| cassette_failed(path, source))?;
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    /// Write the cassette to a JSON file, creating its parent directories
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(|source| cassette_failed(path, source))?;
+        }
+        let contents = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, contents).map_err(|source| cassette_failed(path, source))
+    }
+}
+
+/// One request and the response it received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// URL path and query string, without the scheme and host
+    pub path: String,
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    /// The body when it is JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    /// The body when it is not JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Record,
+    Replay,
+}
+
+struct State {
+    cassette: Cassette,
+    /// Interactions already served in replay mode
+    used: Vec<bool>,
+}
+
+/// Records exchanges to a cassette, or replays them from one
+pub struct Recorder {
+    mode: Mode,
+    path: PathBuf,
+    match_on: Vec<MatchOn>,
+    redacted_fields: Vec<String>,
+    state: Mutex<State>,
+}
+
+impl Recorder {
+    /// Send requests as usual and write every exchange to `path`
+    ///
+    /// The cassette starts empty and the file is rewritten after each
+    /// exchange, so it is complete even if the process stops abruptly.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self::new(Mode::Record, path.into(), Cassette::default())
+    }
+
+    /// Answer requests from the cassette at `path` without network access
+    ///
+    /// Each recorded interaction is served once, in recording order among
+    /// those matching the request. A request with no unused match fails with
+    /// [`BlindPayError::NoRecordedInteraction`].
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        Ok(Self::new(Mode::Replay, path, cassette))
+    }
+
+    fn new(mode: Mode, path: PathBuf, cassette: Cassette) -> Self {
+        Self {
+            mode,
+            path,
+            match_on: vec![MatchOn::Method, MatchOn::Path, MatchOn::Body],
+            redacted_fields: DEFAULT_REDACTED_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            state: Mutex::new(State {
+                used: vec![false; cassette.interactions.len()],
+                cassette,
+            }),
+        }
+    }
+
+    /// Compare only these parts of a request when replaying
+    ///
+    /// Method, path and body are all compared by default.
+    pub fn match_on(mut self, match_on: impl IntoIterator<Item = MatchOn>) -> Self {
+        self.match_on = match_on.into_iter().collect();
+        self
+    }
+
+    /// Also redact body fields and query parameters with this name
+    pub fn redact_field(mut self, field: impl Into<String>) -> Self {
+        self.redacted_fields.push(field.into());
+        self
+    }
+
+    fn matches(&self, recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+        self.match_on.iter().all(|part| match part {
+            MatchOn::Method => recorded.method == request.method,
+            MatchOn::Path => recorded.path == request.path,
+            MatchOn::Body => recorded.body == request.body,
+        })
+    }
+
+    fn record_request(&self, request: &Request, token: Option<&str>) -> RecordedRequest {
+        let url = request.url();
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), self.redact_query(url, query)),
+            None => url.path().to_string(),
+        };
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .filter(|bytes| !bytes.is_empty())
+            .map(|bytes| serde_json::from_slice(bytes).unwrap_or(Value::Null));
+
+        RecordedRequest {
+            method: request.method().to_string(),
+            path: scrub(&path, token),
+            headers: record_headers(request.headers(), token),
+            body: body.map(|body| self.redact(body, token)),
+        }
+    }
+
+    /// The query string with the values of redacted parameters replaced
+    fn redact_query(&self, url: &reqwest::Url, query: &str) -> String {
+        let redacted = |name: &str| self.redacted_fields.iter().any(|field| field == name);
+        if !url.query_pairs().any(|(name, _)| redacted(&name)) {
+            return query.to_string();
+        }
+        let mut url = url.clone();
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(name, value)| {
+                let value = if redacted(&name) {
+                    REDACTED.to_string()
+                } else {
+                    value.into_owned()
+                };
+                (name.into_owned(), value)
+            })
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+        url.query().unwrap_or_default().to_string()
+    }
+
+    async fn record_response(
+        &self,
+        response: Response,
+        request: &RecordedRequest,
+        token: Option<&str>,
+    ) -> Result<(Response, RecordedResponse)> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await?;
+
+        let (body, text) = match serde_json::from_slice::<Value>(&bytes) {
+            Ok(mut body) => {
+                if is_webhook_secret(&request.path) {
+                    redact_fields(&mut body, &[WEBHOOK_SECRET_FIELD.to_string()]);
+                }
+                (Some(self.redact(body, token)), None)
+            }
+            Err(_) if bytes.is_empty() => (None, None),
+            Err(_) => (None, Some(scrub(&String::from_utf8_lossy(&bytes), token))),
+        };
+        let recorded = RecordedResponse {
+            status: status.as_u16(),
+            headers: record_headers(&headers, token),
+            body,
+            text,
+        };
+
+        let mut response = http::Response::new(bytes.to_vec());
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        Ok((Response::from(response), recorded))
+    }
+
+    fn redact(&self, mut value: Value, token: Option<&str>) -> Value {
+        redact_fields(&mut value, &self.redacted_fields);
+        scrub_value(&mut value, token);
+        value
+    }
+
+    fn recorded_response(&self, request: &RecordedRequest) -> Result<Response> {
+        let mut state = self.state.lock().unwrap();
+        let State { cassette, used } = &mut *state;
+        let index = cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .position(|(index, interaction)| {
+                !used[index] && self.matches(&interaction.request, request)
+            })
+            .ok_or_else(|| BlindPayError::NoRecordedInteraction {
+                method: request.method.clone(),
+                path: request.path.clone(),
+            })?;
+        used[index] = true;
+
+        let recorded = &cassette.interactions[index].response;
+        let body = match (&recorded.body, &recorded.text) {
+            (Some(body), _) => serde_json::to_vec(body)?,
+            (None, Some(text)) => text.clone().into_bytes(),
+            (None, None) => Vec::new(),
+        };
+        let mut response = http::Response::new(body);
+        *response.status_mut() = reqwest::StatusCode::from_u16(recorded.status).map_err(|_| {
+            BlindPayError::InvalidConfiguration(format!(
+                "cassette {} has an invalid status {}",
+                self.path.display(),
+                recorded.status
+            ))
+        })?;
+        for (name, value) in &recorded.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                response.headers_mut().append(name, value);
+            }
+        }
+        Ok(Response::from(response))
+    }
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response> {
+        let token = bearer_token(request.headers());
+        let recorded_request = self.record_request(&request, token.as_deref());
+
+        if self.mode == Mode::Replay {
+            return self.recorded_response(&recorded_request);
+        }
+
+        let response = next.run(request).await?;
+        let (response, recorded_response) = self
+            .record_response(response, &recorded_request, token.as_deref())
+            .await?;
+
+        let mut state = self.state.lock().unwrap();
+        state.cassette.interactions.push(Interaction {
+            request: recorded_request,
+            response: recorded_response,
+        });
+        state.cassette.save(&self.path)?;
+        Ok(response)
+    }
+}
+
+fn cassette_failed(path: &Path, source: std::io::Error) -> BlindPayError {
+    BlindPayError::CassetteFailed {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// Whether `path` fetches the signing secret of a webhook endpoint
+fn is_webhook_secret(path: &str) -> bool {
+    let path = path.split('?').next().unwrap_or_default();
+    path.contains("/webhook-endpoints/") && path.ends_with("/secret")
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+}
+
+fn record_headers(headers: &HeaderMap, token: Option<&str>) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| !BODY_FRAMING_HEADERS.contains(&name.as_str()))
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                scrub(&String::from_utf8_lossy(value.as_bytes()), token)
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Replace the value of every non-null field named in `fields`, at any depth
+fn redact_fields(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if fields.iter().any(|name| name == key) && !field.is_null() {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact_fields(field, fields);
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| redact_fields(item, fields)),
+        _ => {}
+    }
+}
+
+/// Replace the API key wherever it appears in a string
+fn scrub(text: &str, token: Option<&str>) -> String {
+    match token {
+        Some(token) => text.replace(token, REDACTED),
+        None => text.to_string(),
+    }
+}
+
+fn scrub_value(value: &mut Value, token: Option<&str>) {
+    match value {
+        Value::String(text) => *text = scrub(text, token),
+        Value::Object(map) => map.values_mut().for_each(|field| scrub_value(field, token)),
+        Value::Array(items) => items.iter_mut().for_each(|item| scrub_value(item, token)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redacts_pii_and_api_key_at_any_depth() {
+        let recorder = Recorder::record("unused.json").redact_field("external_id");
+        let body = json!({
+            "tax_id": "12345678900",
+            "external_id": "user_1",
+            "country": "BR",
+            "last_name": null,
+            "owners": [{ "first_name": "Ana", "role": "beneficial_owner" }],
+            "note": "signed with sk_live_123",
+        });
+
+        let redacted = recorder.redact(body, Some("sk_live_123"));
+        assert_eq!(
+            redacted,
+            json!({
+                "tax_id": REDACTED,
+                "external_id": REDACTED,
+                "country": "BR",
+                "last_name": null,
+                "owners": [{ "first_name": REDACTED, "role": "beneficial_owner" }],
+                "note": "signed with [REDACTED]",
+            })
+        );
+    }
+
+    #[test]
+    fn test_redacts_query_parameters() {
+        let recorder = Recorder::record("unused.json");
+        let url: reqwest::Url =
+            "https://api.blindpay.com/v1/instances/in_1/receivers?email=juan%40example.com&limit=10"
+                .parse()
+                .unwrap();
+        let request = Request::new(reqwest::Method::GET, url);
+
+        let recorded = recorder.record_request(&request, None);
+        assert_eq!(
+            recorded.path,
+            "/v1/instances/in_1/receivers?email=%5BREDACTED%5D&limit=10"
+        );
+    }
+
+    #[test]
+    fn test_webhook_secrets_are_redacted_only_on_their_endpoint() {
+        assert!(is_webhook_secret(
+            "/instances/in_1/webhook-endpoints/we_1/secret"
+        ));
+        assert!(!is_webhook_secret("/available/bank-details?rail=pix"));
+        assert!(!DEFAULT_REDACTED_FIELDS.contains(&WEBHOOK_SECRET_FIELD));
+    }
+
+    #[test]
+    fn test_sensitive_headers_are_redacted() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer sk_live_123"),
+        );
+        headers.insert("x-echo", HeaderValue::from_static("sk_live_123"));
+        headers.insert("content-length", HeaderValue::from_static("42"));
+        headers.insert("x-request-id", HeaderValue::from_static("req_1"));
+
+        let recorded = record_headers(&headers, bearer_token(&headers).as_deref());
+        assert_eq!(recorded["authorization"], REDACTED);
+        assert_eq!(recorded["x-echo"], REDACTED);
+        assert_eq!(recorded["x-request-id"], "req_1");
+        assert!(!recorded.contains_key("content-length"));
+    }
+
+    #[test]
+    fn test_match_rules() {
+        let request = |method: &str, path: &str, body: Option<Value>| RecordedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            headers: BTreeMap::new(),
+            body,
+        };
+        let recorded = request("POST", "/quotes", Some(json!({ "amount": 1 })));
+        let other_body = request("POST", "/quotes", Some(json!({ "amount": 2 })));
+
+        let strict = Recorder::record("unused.json");
+        assert!(strict.matches(&recorded, &recorded.clone()));
+        assert!(!strict.matches(&recorded, &other_body));
+        assert!(!strict.matches(&recorded, &request("GET", "/quotes", None)));
+
+        let lenient = Recorder::record("unused.json").match_on([MatchOn::Method, MatchOn::Path]);
+        assert!(lenient.matches(&recorded, &other_body));
+        assert!(!lenient.matches(&recorded, &request("POST", "/payouts", None)));
+    }
+}
@@ -1,11 +1,14 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::{api_enum, request_options};
+use crate::options::RequestOptions;
+use crate::types::Timestamp;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ApiKeyPermission {
-    FullAccess,
+api_enum! {
+    pub enum ApiKeyPermission {
+        FullAccess => "full_access",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,10 +19,10 @@ pub struct ApiKey {
     pub token: String,
     pub ip_whitelist: Option<Vec<String>>,
     pub unkey_id: String,
-    pub last_used_at: Option<String>,
+    pub last_used_at: Option<Timestamp>,
     pub instance_id: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,11 +40,17 @@ pub struct CreateApiKeyResponse {
 
 pub struct ApiKeysResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(ApiKeysResource);
+
 impl ApiKeysResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List API keys
@@ -79,7 +88,9 @@ impl ApiKeysResource {
     /// ```
     pub async fn create(&self, input: CreateApiKeyInput) -> Result<CreateApiKeyResponse> {
         let path = format!("/instances/{}/api-keys", self.client.instance_id());
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Get an API key by ID
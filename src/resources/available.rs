@@ -1,6 +1,6 @@
 use crate::client::BlindPay;
 use crate::error::Result;
-use crate::types::Rail;
+use crate::types::{Country, Rail};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,7 +23,7 @@ pub struct BankDetailItem {
 pub struct RailInfo {
     pub label: String,
     pub value: Rail,
-    pub country: String,
+    pub country: Country,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,10 +63,8 @@ impl AvailableResource {
     /// # }
     /// ```
     pub async fn get_bank_details(&self, rail: Rail) -> Result<Vec<BankDetail>> {
-        let rail_str = serde_json::to_string(&rail)?;
-        let rail_str = rail_str.trim_matches('"');
         self.client
-            .get(&format!("/available/bank-details?rail={}", rail_str))
+            .get_with_query("/available/bank-details", &[("rail", rail)])
             .await
     }
 
@@ -1,14 +1,20 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::{api_enum, input_builder, request_options};
+use crate::options::RequestOptions;
+use crate::pagination::{paginate, Page, PaginateOptions};
+use crate::resources::available::AvailableResource;
 use crate::types::*;
+use crate::validation::{self, BankAccountInput};
+use futures_util::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum SpeiProtocol {
-    Clabe,
-    Debitcard,
-    Phonenum,
+api_enum! {
+    pub enum SpeiProtocol {
+        Clabe => "clabe",
+        Debitcard => "debitcard",
+        Phonenum => "phonenum",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,7 +73,7 @@ pub struct BankAccount {
     pub swift_intermediary_bank_account_number_iban: Option<String>,
     pub swift_intermediary_bank_name: Option<String>,
     pub swift_intermediary_bank_country: Option<Country>,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -90,7 +96,7 @@ pub struct CreatePixResponse {
     pub account_type: String,
     pub name: String,
     pub pix_key: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // Argentina Transfers
@@ -112,7 +118,7 @@ pub struct CreateArgentinaTransfersResponse {
     pub beneficiary_name: String,
     pub transfers_type: ArgentinaTransfers,
     pub transfers_account: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // SPEI
@@ -136,7 +142,7 @@ pub struct CreateSpeiResponse {
     pub spei_protocol: SpeiProtocol,
     pub spei_institution_code: String,
     pub spei_clabe: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // ACH Colombia
@@ -168,7 +174,7 @@ pub struct CreateColombiaAchResponse {
     pub ach_cop_email: String,
     pub ach_cop_bank_code: String,
     pub ach_cop_bank_account: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // ACH
@@ -194,7 +200,7 @@ pub struct CreateAchResponse {
     pub account_number: String,
     pub account_type: BankAccountType,
     pub account_class: AccountClass,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // Wire
@@ -228,7 +234,7 @@ pub struct CreateWireResponse {
     pub state_province_region: String,
     pub country: Country,
     pub postal_code: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // International SWIFT
@@ -258,6 +264,38 @@ pub struct CreateInternationalSwiftInput {
     pub swift_intermediary_bank_swift_code_bic: Option<String>,
 }
 
+input_builder! {
+    /// Builder for [`CreateInternationalSwiftInput`]
+    pub struct CreateInternationalSwiftInputBuilder => CreateInternationalSwiftInput {
+        required {
+            receiver_id: String,
+            name: String,
+            swift_account_holder_name: String,
+            swift_account_number_iban: String,
+            swift_bank_address_line_1: String,
+            swift_bank_city: String,
+            swift_bank_country: Country,
+            swift_bank_name: String,
+            swift_bank_postal_code: String,
+            swift_bank_state_province_region: String,
+            swift_beneficiary_address_line_1: String,
+            swift_beneficiary_city: String,
+            swift_beneficiary_country: Country,
+            swift_beneficiary_postal_code: String,
+            swift_beneficiary_state_province_region: String,
+            swift_code_bic: String,
+        }
+        optional {
+            swift_bank_address_line_2: String,
+            swift_beneficiary_address_line_2: String,
+            swift_intermediary_bank_account_number_iban: String,
+            swift_intermediary_bank_country: Country,
+            swift_intermediary_bank_name: String,
+            swift_intermediary_bank_swift_code_bic: String,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateInternationalSwiftResponse {
     pub id: String,
@@ -267,7 +305,7 @@ pub struct CreateInternationalSwiftResponse {
     pub swift_code_bic: String,
     pub swift_account_holder_name: String,
     pub swift_account_number_iban: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
 }
 
 // RTP
@@ -301,16 +339,254 @@ pub struct CreateRtpResponse {
     pub state_province_region: String,
     pub country: Country,
     pub postal_code: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
+}
+
+/// Input for [`BankAccountsResource::create`], tagged with the rail of the account
+///
+/// Serialized with a `type` field holding the rail, as the API expects.
+/// Every rail-specific input converts into it with `From`.
+// Inputs are built once and moved straight into a request, so the SWIFT
+// variant is kept unboxed to allow matching on it directly
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CreateBankAccountInput {
+    #[serde(rename = "pix")]
+    Pix(CreatePixInput),
+    #[serde(rename = "transfers_bitso")]
+    ArgentinaTransfers(CreateArgentinaTransfersInput),
+    #[serde(rename = "spei_bitso")]
+    Spei(CreateSpeiInput),
+    #[serde(rename = "ach_cop_bitso")]
+    ColombiaAch(CreateColombiaAchInput),
+    #[serde(rename = "ach")]
+    Ach(CreateAchInput),
+    #[serde(rename = "wire")]
+    Wire(CreateWireInput),
+    #[serde(rename = "international_swift")]
+    InternationalSwift(CreateInternationalSwiftInput),
+    #[serde(rename = "rtp")]
+    Rtp(CreateRtpInput),
+}
+
+impl CreateBankAccountInput {
+    /// Receiver the bank account is created for
+    pub fn receiver_id(&self) -> &str {
+        match self {
+            Self::Pix(input) => &input.receiver_id,
+            Self::ArgentinaTransfers(input) => &input.receiver_id,
+            Self::Spei(input) => &input.receiver_id,
+            Self::ColombiaAch(input) => &input.receiver_id,
+            Self::Ach(input) => &input.receiver_id,
+            Self::Wire(input) => &input.receiver_id,
+            Self::InternationalSwift(input) => &input.receiver_id,
+            Self::Rtp(input) => &input.receiver_id,
+        }
+    }
+}
+
+impl BankAccountInput for CreateBankAccountInput {
+    fn rail(&self) -> Rail {
+        match self {
+            Self::Pix(input) => input.rail(),
+            Self::ArgentinaTransfers(input) => input.rail(),
+            Self::Spei(input) => input.rail(),
+            Self::ColombiaAch(input) => input.rail(),
+            Self::Ach(input) => input.rail(),
+            Self::Wire(input) => input.rail(),
+            Self::InternationalSwift(input) => input.rail(),
+            Self::Rtp(input) => input.rail(),
+        }
+    }
+
+    fn check(&self) -> Vec<FieldError> {
+        match self {
+            Self::Pix(input) => input.check(),
+            Self::ArgentinaTransfers(input) => input.check(),
+            Self::Spei(input) => input.check(),
+            Self::ColombiaAch(input) => input.check(),
+            Self::Ach(input) => input.check(),
+            Self::Wire(input) => input.check(),
+            Self::InternationalSwift(input) => input.check(),
+            Self::Rtp(input) => input.check(),
+        }
+    }
+}
+
+macro_rules! impl_from_input {
+    ($($input:ident => $variant:ident,)*) => {
+        $(
+            impl From<$input> for CreateBankAccountInput {
+                fn from(input: $input) -> Self {
+                    Self::$variant(input)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_input! {
+    CreatePixInput => Pix,
+    CreateArgentinaTransfersInput => ArgentinaTransfers,
+    CreateSpeiInput => Spei,
+    CreateColombiaAchInput => ColombiaAch,
+    CreateAchInput => Ach,
+    CreateWireInput => Wire,
+    CreateInternationalSwiftInput => InternationalSwift,
+    CreateRtpInput => Rtp,
+}
+
+/// The rail-specific part of a [`BankAccount`], from [`BankAccount::details`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum BankAccountDetails {
+    Pix {
+        pix_key: String,
+    },
+    ArgentinaTransfers {
+        beneficiary_name: Option<String>,
+        transfers_type: ArgentinaTransfers,
+        transfers_account: String,
+    },
+    Spei {
+        beneficiary_name: Option<String>,
+        clabe: String,
+        institution_code: Option<String>,
+        protocol: Option<SpeiProtocol>,
+    },
+    ColombiaAch {
+        beneficiary_first_name: Option<String>,
+        beneficiary_last_name: Option<String>,
+        document_id: Option<String>,
+        document_type: Option<AchCopDocument>,
+        email: Option<String>,
+        bank_code: String,
+        bank_account: String,
+        account_type: Option<BankAccountType>,
+    },
+    Ach {
+        beneficiary_name: Option<String>,
+        routing_number: String,
+        account_number: String,
+        account_type: Option<BankAccountType>,
+        account_class: Option<AccountClass>,
+    },
+    Wire {
+        beneficiary_name: Option<String>,
+        routing_number: String,
+        account_number: String,
+    },
+    Rtp {
+        beneficiary_name: Option<String>,
+        routing_number: String,
+        account_number: String,
+    },
+    Swift {
+        account_holder_name: Option<String>,
+        account_number_iban: String,
+        code_bic: String,
+        bank_name: Option<String>,
+        bank_country: Option<Country>,
+        intermediary_bank_code_bic: Option<String>,
+    },
+    /// A rail this version of the SDK does not know, or an account missing
+    /// the fields that identify it on its rail
+    Other {
+        rail: Rail,
+    },
+}
+
+impl BankAccount {
+    /// The rail-specific fields of this account as one typed value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::resources::bank_accounts::{BankAccount, BankAccountDetails};
+    /// # fn example(account: BankAccount) {
+    /// match account.details() {
+    ///     BankAccountDetails::Pix { pix_key } => println!("PIX {}", pix_key),
+    ///     BankAccountDetails::Spei { clabe, .. } => println!("CLABE {}", clabe),
+    ///     other => println!("{:?}", other),
+    /// }
+    /// # }
+    /// ```
+    pub fn details(&self) -> BankAccountDetails {
+        self.try_details()
+            .unwrap_or_else(|| BankAccountDetails::Other {
+                rail: self.account_type.clone(),
+            })
+    }
+
+    fn try_details(&self) -> Option<BankAccountDetails> {
+        let details = match self.account_type {
+            Rail::Pix => BankAccountDetails::Pix {
+                pix_key: self.pix_key.clone()?,
+            },
+            Rail::TransfersBitso => BankAccountDetails::ArgentinaTransfers {
+                beneficiary_name: self.beneficiary_name.clone(),
+                transfers_type: self.transfers_type.clone()?,
+                transfers_account: self.transfers_account.clone()?,
+            },
+            Rail::SpeiBitso => BankAccountDetails::Spei {
+                beneficiary_name: self.beneficiary_name.clone(),
+                clabe: self.spei_clabe.clone()?,
+                institution_code: self.spei_institution_code.clone(),
+                protocol: self.spei_protocol.as_deref().map(SpeiProtocol::from),
+            },
+            Rail::AchCopBitso => BankAccountDetails::ColombiaAch {
+                beneficiary_first_name: self.ach_cop_beneficiary_first_name.clone(),
+                beneficiary_last_name: self.ach_cop_beneficiary_last_name.clone(),
+                document_id: self.ach_cop_document_id.clone(),
+                document_type: self.ach_cop_document_type.clone(),
+                email: self.ach_cop_email.clone(),
+                bank_code: self.ach_cop_bank_code.clone()?,
+                bank_account: self.ach_cop_bank_account.clone()?,
+                account_type: self.account_type_detail.clone(),
+            },
+            Rail::Ach => BankAccountDetails::Ach {
+                beneficiary_name: self.beneficiary_name.clone(),
+                routing_number: self.routing_number.clone()?,
+                account_number: self.account_number.clone()?,
+                account_type: self.account_type_detail.clone(),
+                account_class: self.account_class.clone(),
+            },
+            Rail::Wire => BankAccountDetails::Wire {
+                beneficiary_name: self.beneficiary_name.clone(),
+                routing_number: self.routing_number.clone()?,
+                account_number: self.account_number.clone()?,
+            },
+            Rail::Rtp => BankAccountDetails::Rtp {
+                beneficiary_name: self.beneficiary_name.clone(),
+                routing_number: self.routing_number.clone()?,
+                account_number: self.account_number.clone()?,
+            },
+            Rail::InternationalSwift => BankAccountDetails::Swift {
+                account_holder_name: self.swift_account_holder_name.clone(),
+                account_number_iban: self.swift_account_number_iban.clone()?,
+                code_bic: self.swift_code_bic.clone()?,
+                bank_name: self.swift_bank_name.clone(),
+                bank_country: self.swift_bank_country.clone(),
+                intermediary_bank_code_bic: self.swift_intermediary_bank_swift_code_bic.clone(),
+            },
+            Rail::Unknown(_) => return None,
+        };
+        Some(details)
+    }
 }
 
 pub struct BankAccountsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(BankAccountsResource);
+
 impl BankAccountsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List bank accounts for a receiver
@@ -333,6 +609,33 @@ impl BankAccountsResource {
         self.client.get(&path).await
     }
 
+    /// Stream every bank account of a receiver
+    ///
+    /// The bank accounts endpoint returns all accounts in one response, so
+    /// `page_size` has no effect; `max_items` still limits the output.
+    pub fn list_stream(
+        &self,
+        receiver_id: &str,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<BankAccount>> {
+        let client = self.client.clone();
+        let receiver_id = receiver_id.to_string();
+        paginate(PaginationParams::default(), options, move |_| {
+            let resource = BankAccountsResource::new(client.clone());
+            let receiver_id = receiver_id.clone();
+            async move { Ok(Page::single(resource.list(&receiver_id).await?.data)) }
+        })
+    }
+
+    /// Collect every bank account of a receiver into memory
+    pub async fn list_all(
+        &self,
+        receiver_id: &str,
+        options: PaginateOptions,
+    ) -> Result<Vec<BankAccount>> {
+        self.list_stream(receiver_id, options).try_collect().await
+    }
+
     /// Get a bank account by ID
     pub async fn get(&self, receiver_id: &str, id: &str) -> Result<BankAccount> {
         let path = format!(
@@ -355,7 +658,35 @@ impl BankAccountsResource {
         self.client.delete(&path).await
     }
 
-    /// Create a PIX bank account
+    /// Check a bank account input before creating it
+    ///
+    /// Fetches the field rules of the input's rail and applies them with the
+    /// built-in checks of [`validation::validate`](crate::validation::validate).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::resources::bank_accounts::CreatePixInput;
+    /// # async fn example(input: CreatePixInput) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let bank_accounts = client.receivers().bank_accounts();
+    /// bank_accounts.validate(&input).await?;
+    /// let account = bank_accounts.create_pix(input).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate<I: BankAccountInput>(&self, input: &I) -> Result<()> {
+        let rules = AvailableResource::new(self.client.clone())
+            .get_bank_details(input.rail())
+            .await?;
+        validation::validate(input, &rules)
+    }
+
+    /// Create a bank account on any rail
+    ///
+    /// Accepts a [`CreateBankAccountInput`] or any rail-specific input, and
+    /// returns the created account; use [`BankAccount::details`] to inspect
+    /// its rail-specific fields.
     ///
     /// # Example
     /// ```no_run
@@ -368,20 +699,46 @@ impl BankAccountsResource {
     ///     name: "My PIX Account".to_string(),
     ///     pix_key: "14947677768".to_string(),
     /// };
-    /// let account = client.receivers().bank_accounts().create_pix(input).await?;
+    /// let account = client.receivers().bank_accounts().create(input).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_pix(&self, input: CreatePixInput) -> Result<CreatePixResponse> {
-        let receiver_id = input.receiver_id.clone();
+    pub async fn create(&self, input: impl Into<CreateBankAccountInput>) -> Result<BankAccount> {
+        self.post_account(input.into(), &self.options).await
+    }
+
+    async fn post_account<R: serde::de::DeserializeOwned>(
+        &self,
+        input: CreateBankAccountInput,
+        options: &RequestOptions,
+    ) -> Result<R> {
         let path = format!(
             "/instances/{}/receivers/{}/bank-accounts",
             self.client.instance_id(),
-            receiver_id
+            input.receiver_id()
         );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("pix");
-        self.client.post(&path, body).await
+        self.client.post_with_options(&path, &input, options).await
+    }
+
+    /// Create a PIX bank account
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::resources::bank_accounts::CreatePixInput;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let input = CreatePixInput {
+    ///     receiver_id: "re_123".to_string(),
+    ///     name: "My PIX Account".to_string(),
+    ///     pix_key: "14947677768".to_string(),
+    /// };
+    /// let account = client.receivers().bank_accounts().create_pix(input).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_pix(&self, input: CreatePixInput) -> Result<CreatePixResponse> {
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create an Argentina Transfers bank account
@@ -389,28 +746,12 @@ impl BankAccountsResource {
         &self,
         input: CreateArgentinaTransfersInput,
     ) -> Result<CreateArgentinaTransfersResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
-        );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("transfers_bitso");
-        self.client.post(&path, body).await
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create a SPEI bank account
     pub async fn create_spei(&self, input: CreateSpeiInput) -> Result<CreateSpeiResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
-        );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("spei_bitso");
-        self.client.post(&path, body).await
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create a Colombia ACH bank account
@@ -418,41 +759,17 @@ impl BankAccountsResource {
         &self,
         input: CreateColombiaAchInput,
     ) -> Result<CreateColombiaAchResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
-        );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("ach_cop_bitso");
-        self.client.post(&path, body).await
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create an ACH bank account
     pub async fn create_ach(&self, input: CreateAchInput) -> Result<CreateAchResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
-        );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("ach");
-        self.client.post(&path, body).await
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create a Wire bank account
     pub async fn create_wire(&self, input: CreateWireInput) -> Result<CreateWireResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
-        );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("wire");
-        self.client.post(&path, body).await
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create an International SWIFT bank account
@@ -460,27 +777,82 @@ impl BankAccountsResource {
         &self,
         input: CreateInternationalSwiftInput,
     ) -> Result<CreateInternationalSwiftResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
-        );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("international_swift");
-        self.client.post(&path, body).await
+        self.post_account(input.into(), &self.options).await
     }
 
     /// Create an RTP bank account
     pub async fn create_rtp(&self, input: CreateRtpInput) -> Result<CreateRtpResponse> {
-        let receiver_id = input.receiver_id.clone();
-        let path = format!(
-            "/instances/{}/receivers/{}/bank-accounts",
-            self.client.instance_id(),
-            receiver_id
+        self.post_account(input.into(), &self.options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(json: serde_json::Value) -> BankAccount {
+        let mut base = serde_json::json!({
+            "id": "ba_123",
+            "name": "Main",
+            "created_at": "2025-01-01T00:00:00Z"
+        });
+        base.as_object_mut()
+            .unwrap()
+            .extend(json.as_object().unwrap().clone());
+        serde_json::from_value(base).unwrap()
+    }
+
+    #[test]
+    fn test_create_input_is_tagged_with_rail() {
+        let input = CreateBankAccountInput::from(CreatePixInput {
+            receiver_id: "re_123".to_string(),
+            name: "PIX".to_string(),
+            pix_key: "14947677768".to_string(),
+        });
+
+        let json = serde_json::to_value(&input).unwrap();
+        assert_eq!(json["type"], "pix");
+        assert_eq!(json["pix_key"], "14947677768");
+        assert_eq!(input.rail(), Rail::Pix);
+        assert_eq!(input.receiver_id(), "re_123");
+
+        let back: CreateBankAccountInput = serde_json::from_value(json).unwrap();
+        assert!(matches!(back, CreateBankAccountInput::Pix(pix) if pix.name == "PIX"));
+    }
+
+    #[test]
+    fn test_details_matches_rail() {
+        let pix = account(serde_json::json!({"type": "pix", "pix_key": "key"}));
+        assert_eq!(
+            pix.details(),
+            BankAccountDetails::Pix {
+                pix_key: "key".to_string()
+            }
+        );
+
+        let spei = account(serde_json::json!({
+            "type": "spei_bitso",
+            "spei_clabe": "002010077777777771",
+            "spei_protocol": "clabe"
+        }));
+        assert!(matches!(
+            spei.details(),
+            BankAccountDetails::Spei { clabe, protocol: Some(SpeiProtocol::Clabe), .. }
+                if clabe == "002010077777777771"
+        ));
+
+        // Missing identifying fields and unknown rails fall back to Other
+        let incomplete = account(serde_json::json!({"type": "wire", "account_number": "123"}));
+        assert_eq!(
+            incomplete.details(),
+            BankAccountDetails::Other { rail: Rail::Wire }
+        );
+        let unknown = account(serde_json::json!({"type": "faster_payments"}));
+        assert_eq!(
+            unknown.details(),
+            BankAccountDetails::Other {
+                rail: Rail::Unknown("faster_payments".to_string())
+            }
         );
-        let mut body = serde_json::to_value(input)?;
-        body["type"] = serde_json::json!("rtp");
-        self.client.post(&path, body).await
     }
 }
@@ -1,17 +1,19 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::api_enum;
+use crate::types::Timestamp;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum InstanceMemberRole {
-    Owner,
-    Admin,
-    Finance,
-    Checker,
-    Operations,
-    Developer,
-    Viewer,
+api_enum! {
+    pub enum InstanceMemberRole {
+        Owner => "owner",
+        Admin => "admin",
+        Finance => "finance",
+        Checker => "checker",
+        Operations => "operations",
+        Developer => "developer",
+        Viewer => "viewer",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,7 +24,7 @@ pub struct InstanceMember {
     pub middle_name: String,
     pub last_name: String,
     pub image_url: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
     pub role: InstanceMemberRole,
 }
 
@@ -149,16 +151,16 @@ impl InstancesResource {
 }
 
 // API Keys sub-resource
-use crate::resources::api_keys::ApiKeysResource;
 pub use crate::resources::api_keys;
+use crate::resources::api_keys::ApiKeysResource;
 
 // Webhooks sub-resource
-use crate::resources::webhooks::WebhookEndpointsResource;
 pub use crate::resources::webhooks;
+use crate::resources::webhooks::WebhookEndpointsResource;
 
 // Terms of Service sub-resource
-use crate::resources::terms_of_service::TermsOfServiceResource;
 pub use crate::resources::terms_of_service;
+use crate::resources::terms_of_service::TermsOfServiceResource;
 
 impl InstancesResource {
     /// Access API keys sub-resource
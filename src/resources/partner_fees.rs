@@ -1,5 +1,8 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::request_options;
+use crate::money::Amount;
+use crate::options::RequestOptions;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,10 +10,10 @@ pub struct PartnerFee {
     pub id: String,
     pub instance_id: String,
     pub name: String,
-    pub payout_percentage_fee: f64,
-    pub payout_flat_fee: f64,
-    pub payin_percentage_fee: f64,
-    pub payin_flat_fee: f64,
+    pub payout_percentage_fee: Amount,
+    pub payout_flat_fee: Amount,
+    pub payin_percentage_fee: Amount,
+    pub payin_flat_fee: Amount,
     pub evm_wallet_address: String,
     pub stellar_wallet_address: Option<String>,
 }
@@ -18,10 +21,10 @@ pub struct PartnerFee {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePartnerFeeInput {
     pub name: String,
-    pub payout_percentage_fee: f64,
-    pub payout_flat_fee: f64,
-    pub payin_percentage_fee: f64,
-    pub payin_flat_fee: f64,
+    pub payout_percentage_fee: Amount,
+    pub payout_flat_fee: Amount,
+    pub payin_percentage_fee: Amount,
+    pub payin_flat_fee: Amount,
     pub evm_wallet_address: String,
     pub stellar_wallet_address: Option<String>,
     pub virtual_account_set: Option<bool>,
@@ -29,11 +32,17 @@ pub struct CreatePartnerFeeInput {
 
 pub struct PartnerFeesResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(PartnerFeesResource);
+
 impl PartnerFeesResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List partner fees
@@ -45,7 +54,9 @@ impl PartnerFeesResource {
     /// Create a partner fee
     pub async fn create(&self, input: CreatePartnerFeeInput) -> Result<PartnerFee> {
         let path = format!("/instances/{}/partner-fees", self.client.instance_id());
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Get a partner fee by ID
@@ -1,11 +1,17 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::money::{Amount, Money};
+use crate::options::RequestOptions;
+use crate::pagination::{paginate, Page, PaginateOptions, Paginated};
+use crate::polling::{self, Trackable, TrackingStage, TrackingUpdate, WaitOptions};
 use crate::types::*;
+use futures_util::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 // Re-export payin quotes
-use crate::resources::quotes::PayinQuotesResource;
+use crate::macros::request_options;
 pub use crate::resources::quotes;
+use crate::resources::quotes::PayinQuotesResource;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payin {
@@ -18,50 +24,223 @@ pub struct Payin {
     pub tracking_payment: PayinTrackingPayment,
     pub tracking_complete: PayinTrackingComplete,
     pub tracking_partner_fee: Option<PayinTrackingPartnerFee>,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
     pub payment_method: PayinPaymentMethod,
-    pub sender_amount: f64,
-    pub receiver_amount: f64,
+    pub sender_amount: Amount,
+    pub receiver_amount: Amount,
     pub token: StablecoinToken,
     pub currency: Currency,
     pub network: Network,
 }
 
+impl Payin {
+    /// Amount paid by the sender, in fiat
+    pub fn sender_money(&self) -> Money {
+        Money::new(self.sender_amount, self.currency.clone())
+    }
+
+    /// Amount delivered to the receiver wallet, in its stablecoin
+    pub fn receiver_money(&self) -> Money {
+        Money::new(self.receiver_amount, self.token.clone().into())
+    }
+}
+
+impl Trackable for Payin {
+    fn status(&self) -> &TransactionStatus {
+        &self.status
+    }
+
+    fn tracking_steps(&self) -> Vec<(TrackingStage, TrackingStatus)> {
+        let mut steps = vec![
+            (
+                TrackingStage::Transaction,
+                self.tracking_transaction.step.clone(),
+            ),
+            (TrackingStage::Payment, self.tracking_payment.step.clone()),
+            (TrackingStage::Complete, self.tracking_complete.step.clone()),
+        ];
+        if let Some(partner_fee) = &self.tracking_partner_fee {
+            steps.push((TrackingStage::PartnerFee, partner_fee.step.clone()));
+        }
+        steps
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListPayinsResponse {
     pub data: Vec<Payin>,
     pub pagination: PaginationMetadata,
 }
 
+/// Filters and pagination for [`PayinsResource::list`]
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::resources::payins::ListPayinsParams;
+/// # use blindpay::types::{Network, TransactionStatus};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let params = ListPayinsParams::new()
+///     .receiver_id("re_123")
+///     .status(TransactionStatus::Completed)
+///     .network(Network::Polygon)
+///     .created_after("2025-01-01T00:00:00Z")
+///     .created_before("2025-02-01T00:00:00Z");
+/// let payins = client.payins().list(Some(params)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListPayinsParams {
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TransactionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Only payins created at or after this ISO 8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<Timestamp>,
+    /// Only payins created before this ISO 8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+impl ListPayinsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of payins per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.pagination.limit = Some(limit);
+        self
+    }
+
+    pub fn receiver_id(mut self, receiver_id: impl Into<String>) -> Self {
+        self.receiver_id = Some(receiver_id.into());
+        self
+    }
+
+    pub fn status(mut self, status: TransactionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub fn created_after(mut self, created_after: impl Into<Timestamp>) -> Self {
+        self.created_after = Some(created_after.into());
+        self
+    }
+
+    pub fn created_before(mut self, created_before: impl Into<Timestamp>) -> Self {
+        self.created_before = Some(created_before.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+impl From<PaginationParams> for ListPayinsParams {
+    fn from(pagination: PaginationParams) -> Self {
+        Self {
+            pagination,
+            ..Self::default()
+        }
+    }
+}
+
+impl Paginated for ListPayinsParams {
+    fn pagination_mut(&mut self) -> &mut PaginationParams {
+        &mut self.pagination
+    }
+}
+
 pub struct PayinsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(PayinsResource);
+
 impl PayinsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List payins
-    pub async fn list(&self, params: Option<PaginationParams>) -> Result<ListPayinsResponse> {
-        let mut path = format!("/instances/{}/payins", self.client.instance_id());
-        
-        if let Some(p) = params {
-            let mut query_params = vec![];
-            if let Some(limit) = p.limit {
-                query_params.push(format!("limit={}", limit));
-            }
-            if let Some(offset) = p.offset {
-                query_params.push(format!("offset={}", offset));
-            }
-            if !query_params.is_empty() {
-                path.push('?');
-                path.push_str(&query_params.join("&"));
+    pub async fn list(&self, params: Option<ListPayinsParams>) -> Result<ListPayinsResponse> {
+        let path = format!("/instances/{}/payins", self.client.instance_id());
+        self.client
+            .get_with_query(&path, &params.unwrap_or_default())
+            .await
+    }
+
+    /// Stream every payin, following pagination cursors automatically
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::pagination::PaginateOptions;
+    /// # use futures_util::TryStreamExt;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let mut payins = Box::pin(client.payins().list_stream(None, PaginateOptions::new().page_size(100)));
+    /// while let Some(payin) = payins.try_next().await? {
+    ///     println!("{}", payin.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_stream(
+        &self,
+        params: Option<ListPayinsParams>,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Payin>> {
+        let client = self.client.clone();
+        paginate(params.unwrap_or_default(), options, move |params| {
+            let resource = PayinsResource::new(client.clone());
+            async move {
+                let response = resource.list(Some(params)).await?;
+                Ok(Page::with_cursor(
+                    response.data,
+                    response.pagination.has_more,
+                    |payin| &payin.id,
+                ))
             }
-        }
-        
-        self.client.get(&path).await
+        })
+    }
+
+    /// Collect every payin into memory, following pagination cursors automatically
+    pub async fn list_all(
+        &self,
+        params: Option<ListPayinsParams>,
+        options: PaginateOptions,
+    ) -> Result<Vec<Payin>> {
+        self.list_stream(params, options).try_collect().await
     }
 
     /// Get a payin by ID
@@ -74,6 +253,52 @@ impl PayinsResource {
         self.client.get(&path).await
     }
 
+    /// Poll a payin until it is completed, failed or refunded, yielding an
+    /// update each time its status or a tracking step changes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::polling::WaitOptions;
+    /// # use futures_util::TryStreamExt;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let mut updates = Box::pin(client.payins().watch("pi_123", WaitOptions::new()));
+    /// while let Some(update) = updates.try_next().await? {
+    ///     println!("{:?} changed: {:?}", update.current.status, update.changed);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch(
+        &self,
+        payin_id: &str,
+        options: WaitOptions,
+    ) -> impl Stream<Item = Result<TrackingUpdate<Payin>>> {
+        let client = self.client.clone();
+        let id = payin_id.to_string();
+        polling::watch(id.clone(), options, move || {
+            let resource = PayinsResource::new(client.clone());
+            let id = id.clone();
+            async move { resource.get(&id).await }
+        })
+    }
+
+    /// Poll a payin until it is completed, failed or refunded and return it
+    ///
+    /// Fails with [`BlindPayError::WaitTimedOut`](crate::BlindPayError::WaitTimedOut)
+    /// when the payin is still in progress after the configured timeout.
+    pub async fn wait_for_completion(&self, payin_id: &str, options: WaitOptions) -> Result<Payin> {
+        let client = self.client.clone();
+        let id = payin_id.to_string();
+        polling::wait_for_completion(id.clone(), options, move || {
+            let resource = PayinsResource::new(client.clone());
+            let id = id.clone();
+            async move { resource.get(&id).await }
+        })
+        .await
+    }
+
     /// Get payin tracking information
     pub async fn get_track(&self, payin_id: &str) -> Result<Payin> {
         let path = format!("/e/payins/{}", payin_id);
@@ -84,7 +309,9 @@ impl PayinsResource {
     pub async fn create_evm(&self, payin_quote_id: &str) -> Result<Payin> {
         let path = format!("/instances/{}/payins/evm", self.client.instance_id());
         let body = serde_json::json!({ "payin_quote_id": payin_quote_id });
-        self.client.post(&path, body).await
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 
     /// Access payin quotes sub-resource
@@ -1,7 +1,16 @@
 use crate::client::BlindPay;
-use crate::error::Result;
+use crate::error::{BlindPayError, Result};
+use crate::macros::request_options;
+use crate::money::{Amount, Money};
+use crate::options::RequestOptions;
+use crate::pagination::{paginate, Page, PaginateOptions, Paginated};
+use crate::polling::{self, Trackable, TrackingStage, TrackingUpdate, WaitOptions};
+use crate::resources::quotes::{CreateQuoteInput, CreateQuoteResponse, QuotesResource};
+use crate::signing::{EvmPayoutSigner, EvmSigner, PayoutSigner};
 use crate::types::*;
+use futures_util::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payout {
@@ -17,8 +26,8 @@ pub struct Payout {
     pub tracking_liquidity: PayoutTrackingLiquidity,
     pub tracking_complete: PayoutTrackingComplete,
     pub tracking_partner_fee: PayoutTrackingPartnerFee,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
     pub image_url: Option<String>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
@@ -26,22 +35,162 @@ pub struct Payout {
     pub network: Network,
     pub token: StablecoinToken,
     pub description: String,
-    pub sender_amount: f64,
-    pub receiver_amount: f64,
-    pub partner_fee_amount: f64,
-    pub commercial_quotation: f64,
-    pub blindpay_quotation: f64,
-    pub total_fee_amount: f64,
-    pub receiver_local_amount: f64,
+    pub sender_amount: Amount,
+    pub receiver_amount: Amount,
+    pub partner_fee_amount: Amount,
+    pub commercial_quotation: Amount,
+    pub blindpay_quotation: Amount,
+    pub total_fee_amount: Amount,
+    pub receiver_local_amount: Amount,
     pub currency: Currency,
 }
 
+impl Payout {
+    /// Amount debited from the sender wallet, in its stablecoin
+    pub fn sender_money(&self) -> Money {
+        Money::new(self.sender_amount, self.token.clone().into())
+    }
+
+    /// Amount delivered to the bank account, in the local currency
+    pub fn receiver_local_money(&self) -> Money {
+        Money::new(self.receiver_local_amount, self.currency.clone())
+    }
+}
+
+impl Trackable for Payout {
+    fn status(&self) -> &TransactionStatus {
+        &self.status
+    }
+
+    fn tracking_steps(&self) -> Vec<(TrackingStage, TrackingStatus)> {
+        vec![
+            (
+                TrackingStage::Transaction,
+                self.tracking_transaction.step.clone(),
+            ),
+            (TrackingStage::Payment, self.tracking_payment.step.clone()),
+            (
+                TrackingStage::Liquidity,
+                self.tracking_liquidity.step.clone(),
+            ),
+            (TrackingStage::Complete, self.tracking_complete.step.clone()),
+            (
+                TrackingStage::PartnerFee,
+                self.tracking_partner_fee.step.clone(),
+            ),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListPayoutsResponse {
     pub data: Vec<Payout>,
     pub pagination: PaginationMetadata,
 }
 
+/// Filters and pagination for [`PayoutsResource::list`]
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::resources::payouts::ListPayoutsParams;
+/// # use blindpay::types::{Network, TransactionStatus};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let params = ListPayoutsParams::new()
+///     .receiver_id("re_123")
+///     .status(TransactionStatus::Completed)
+///     .network(Network::Polygon)
+///     .created_after("2025-01-01T00:00:00Z")
+///     .created_before("2025-02-01T00:00:00Z");
+/// let payouts = client.payouts().list(Some(params)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListPayoutsParams {
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TransactionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Only payouts created at or after this ISO 8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<Timestamp>,
+    /// Only payouts created before this ISO 8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+impl ListPayoutsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of payouts per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.pagination.limit = Some(limit);
+        self
+    }
+
+    pub fn receiver_id(mut self, receiver_id: impl Into<String>) -> Self {
+        self.receiver_id = Some(receiver_id.into());
+        self
+    }
+
+    pub fn status(mut self, status: TransactionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub fn created_after(mut self, created_after: impl Into<Timestamp>) -> Self {
+        self.created_after = Some(created_after.into());
+        self
+    }
+
+    pub fn created_before(mut self, created_before: impl Into<Timestamp>) -> Self {
+        self.created_before = Some(created_before.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+impl From<PaginationParams> for ListPayoutsParams {
+    fn from(pagination: PaginationParams) -> Self {
+        Self {
+            pagination,
+            ..Self::default()
+        }
+    }
+}
+
+impl Paginated for ListPayoutsParams {
+    fn pagination_mut(&mut self) -> &mut PaginationParams {
+        &mut self.pagination
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateStellarPayoutInput {
     pub quote_id: String,
@@ -62,13 +211,113 @@ pub struct CreatePayoutResponse {
     pub receiver_id: String,
 }
 
+/// Token authorization transaction returned by
+/// [`PayoutsResource::authorize_stellar_token`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizeStellarTokenResponse {
+    /// Base64 transaction envelope XDR to sign with the sender wallet
+    pub transaction_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepareSolanaDelegationInput {
+    pub owner_address: String,
+    pub token_address: String,
+    /// Amount in token base units
+    pub amount: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepareSolanaDelegationResponse {
+    pub success: bool,
+    /// Base64 serialized transaction to sign with the owner wallet
+    pub transaction: String,
+}
+
+/// Controls [`PayoutsResource::execute`] and [`PayoutsResource::execute_evm`]
+#[derive(Debug, Clone)]
+pub struct ExecutePayoutOptions {
+    min_quote_validity: Duration,
+    wait: Option<WaitOptions>,
+    idempotency_key: Option<String>,
+}
+
+impl Default for ExecutePayoutOptions {
+    /// Require 10 seconds of quote validity and return without waiting
+    fn default() -> Self {
+        Self {
+            min_quote_validity: Duration::from_secs(10),
+            wait: None,
+            idempotency_key: None,
+        }
+    }
+}
+
+impl ExecutePayoutOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fail with [`BlindPayError::QuoteExpired`] instead of submitting when
+    /// the quote expires in less than this
+    pub fn min_quote_validity(mut self, min_quote_validity: Duration) -> Self {
+        self.min_quote_validity = min_quote_validity;
+        self
+    }
+
+    /// Wait for the payout to reach a terminal status before returning
+    pub fn wait_for_completion(mut self, options: WaitOptions) -> Self {
+        self.wait = Some(options);
+        self
+    }
+
+    /// `Idempotency-Key` sent when creating the payout; a random one is
+    /// generated when unset
+    ///
+    /// Pass the key from [`BlindPayError::idempotency_key`] to resume a call
+    /// that failed with [`BlindPayError::PayoutStatusUnknown`]: the API then
+    /// returns the payout created the first time instead of a second one.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+}
+
+/// Result of [`PayoutsResource::execute`] and [`PayoutsResource::execute_evm`]
+#[derive(Debug, Clone)]
+pub struct ExecutedPayout {
+    /// The quote the payout was created from
+    pub quote: CreateQuoteResponse,
+    /// What the signer produced: the approval transaction hash on EVM
+    /// networks, the signed transaction on Stellar and Solana
+    pub approval_transaction: String,
+    /// The payout, in its terminal state when waiting was requested
+    pub payout: Payout,
+}
+
+fn ensure_quote_valid(quote: &CreateQuoteResponse, min_validity: Duration) -> Result<()> {
+    if quote.time_remaining() < min_validity {
+        return Err(BlindPayError::QuoteExpired {
+            quote_id: quote.id.clone(),
+        });
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
 pub struct PayoutsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(PayoutsResource);
+
 impl PayoutsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List payouts
@@ -82,24 +331,55 @@ impl PayoutsResource {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(&self, params: Option<PaginationParams>) -> Result<ListPayoutsResponse> {
-        let mut path = format!("/instances/{}/payouts", self.client.instance_id());
-        
-        if let Some(p) = params {
-            let mut query_params = vec![];
-            if let Some(limit) = p.limit {
-                query_params.push(format!("limit={}", limit));
-            }
-            if let Some(offset) = p.offset {
-                query_params.push(format!("offset={}", offset));
-            }
-            if !query_params.is_empty() {
-                path.push('?');
-                path.push_str(&query_params.join("&"));
+    pub async fn list(&self, params: Option<ListPayoutsParams>) -> Result<ListPayoutsResponse> {
+        let path = format!("/instances/{}/payouts", self.client.instance_id());
+        self.client
+            .get_with_query(&path, &params.unwrap_or_default())
+            .await
+    }
+
+    /// Stream every payout, following pagination cursors automatically
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::pagination::PaginateOptions;
+    /// # use futures_util::TryStreamExt;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let mut payouts = Box::pin(client.payouts().list_stream(None, PaginateOptions::new().page_size(100)));
+    /// while let Some(payout) = payouts.try_next().await? {
+    ///     println!("{}", payout.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_stream(
+        &self,
+        params: Option<ListPayoutsParams>,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Payout>> {
+        let client = self.client.clone();
+        paginate(params.unwrap_or_default(), options, move |params| {
+            let resource = PayoutsResource::new(client.clone());
+            async move {
+                let response = resource.list(Some(params)).await?;
+                Ok(Page::with_cursor(
+                    response.data,
+                    response.pagination.has_more,
+                    |payout| &payout.id,
+                ))
             }
-        }
-        
-        self.client.get(&path).await
+        })
+    }
+
+    /// Collect every payout into memory, following pagination cursors automatically
+    pub async fn list_all(
+        &self,
+        params: Option<ListPayoutsParams>,
+        options: PaginateOptions,
+    ) -> Result<Vec<Payout>> {
+        self.list_stream(params, options).try_collect().await
     }
 
     /// Get a payout by ID
@@ -122,6 +402,56 @@ impl PayoutsResource {
         self.client.get(&path).await
     }
 
+    /// Poll a payout until it is completed, failed or refunded, yielding an
+    /// update each time its status or a tracking step changes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::polling::WaitOptions;
+    /// # use futures_util::TryStreamExt;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let mut updates = Box::pin(client.payouts().watch("pa_123", WaitOptions::new()));
+    /// while let Some(update) = updates.try_next().await? {
+    ///     println!("{:?} changed: {:?}", update.current.status, update.changed);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch(
+        &self,
+        payout_id: &str,
+        options: WaitOptions,
+    ) -> impl Stream<Item = Result<TrackingUpdate<Payout>>> {
+        let client = self.client.clone();
+        let id = payout_id.to_string();
+        polling::watch(id.clone(), options, move || {
+            let resource = PayoutsResource::new(client.clone());
+            let id = id.clone();
+            async move { resource.get(&id).await }
+        })
+    }
+
+    /// Poll a payout until it is completed, failed or refunded and return it
+    ///
+    /// Fails with [`BlindPayError::WaitTimedOut`](crate::BlindPayError::WaitTimedOut)
+    /// when the payout is still in progress after the configured timeout.
+    pub async fn wait_for_completion(
+        &self,
+        payout_id: &str,
+        options: WaitOptions,
+    ) -> Result<Payout> {
+        let client = self.client.clone();
+        let id = payout_id.to_string();
+        polling::wait_for_completion(id.clone(), options, move || {
+            let resource = PayoutsResource::new(client.clone());
+            let id = id.clone();
+            async move { resource.get(&id).await }
+        })
+        .await
+    }
+
     /// Get payout tracking information
     pub async fn get_track(&self, payout_id: &str) -> Result<Payout> {
         let path = format!("/e/payouts/{}", payout_id);
@@ -150,7 +480,9 @@ impl PayoutsResource {
         input: CreateStellarPayoutInput,
     ) -> Result<CreatePayoutResponse> {
         let path = format!("/instances/{}/payouts/stellar", self.client.instance_id());
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Create an EVM payout
@@ -164,9 +496,159 @@ impl PayoutsResource {
             "quote_id": quote_id,
             "sender_wallet_address": sender_wallet_address,
         });
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
+    }
+
+    /// Quote, authorize and create a payout in one call
+    ///
+    /// Creates the quote, checks it is valid for at least
+    /// `min_quote_validity`, lets `signer` prepare and sign the transaction
+    /// authorizing the payout, checks the quote again and submits the payout.
+    /// Fails with [`BlindPayError::InvalidConfiguration`] when the signer does
+    /// not handle the quoted network.
+    ///
+    /// The payout is created with one idempotency key, reused by every retry.
+    /// When the creation may have gone through, a failure to create, fetch or
+    /// wait for the payout is returned as [`BlindPayError::PayoutStatusUnknown`]
+    /// with that key and, once known, the payout id. Do not call `execute`
+    /// again without passing the key through
+    /// [`ExecutePayoutOptions::idempotency_key`], as that could pay twice.
+    pub async fn execute<S: PayoutSigner + ?Sized>(
+        &self,
+        input: CreateQuoteInput,
+        signer: &S,
+        options: ExecutePayoutOptions,
+    ) -> Result<ExecutedPayout> {
+        if !signer.supports(&input.network) {
+            return Err(BlindPayError::InvalidConfiguration(format!(
+                "the signer does not support the {} network",
+                input.network
+            )));
+        }
+
+        let quote = QuotesResource::new(self.client.clone())
+            .create(input)
+            .await?;
+        ensure_quote_valid(&quote, options.min_quote_validity)?;
+
+        let transaction = signer.prepare(self, &quote).await?;
+        let approval_transaction = signer.sign(transaction).await?;
+        // Signing and mining can take a while
+        ensure_quote_valid(&quote, options.min_quote_validity)?;
+
+        let idempotency_key = options
+            .idempotency_key
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let payouts = self
+            .clone()
+            .with_options(RequestOptions::new().idempotency_key(&idempotency_key));
+        let created = match signer
+            .submit(&payouts, &quote, approval_transaction.clone())
+            .await
+        {
+            Ok(created) => created,
+            // The API turned the request down, so no payout was created
+            Err(err) if err.status().is_some_and(|status| status.is_client_error()) => {
+                return Err(err)
+            }
+            Err(source) => {
+                return Err(BlindPayError::PayoutStatusUnknown {
+                    payout_id: None,
+                    idempotency_key,
+                    source: Box::new(source),
+                })
+            }
+        };
+        let payout = match options.wait {
+            Some(wait) => self.wait_for_completion(&created.id, wait).await,
+            None => self.get(&created.id).await,
+        }
+        .map_err(|source| BlindPayError::PayoutStatusUnknown {
+            payout_id: Some(created.id),
+            idempotency_key,
+            source: Box::new(source),
+        })?;
+
+        Ok(ExecutedPayout {
+            quote,
+            approval_transaction,
+            payout,
+        })
+    }
+
+    /// Quote, approve and create an EVM payout in one call
+    ///
+    /// Shorthand for [`execute`](Self::execute) with an [`EvmPayoutSigner`]:
+    /// `signer` sends the token approval described by the quote's contract
+    /// and the payout is created from its address.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::polling::WaitOptions;
+    /// # use blindpay::resources::payouts::ExecutePayoutOptions;
+    /// # use blindpay::resources::quotes::CreateQuoteInput;
+    /// # use blindpay::signing::EvmSigner;
+    /// # use blindpay::types::{CurrencyType, Network, StablecoinToken};
+    /// # async fn example(wallet: impl EvmSigner) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let input = CreateQuoteInput::builder()
+    ///     .bank_account_id("ba_123")
+    ///     .currency_type(CurrencyType::Sender)
+    ///     .cover_fees(false)
+    ///     .request_amount(1000)
+    ///     .network(Network::Base)
+    ///     .token(StablecoinToken::USDC)
+    ///     .build()?;
+    /// let options = ExecutePayoutOptions::new().wait_for_completion(WaitOptions::new());
+    /// let executed = client.payouts().execute_evm(input, &wallet, options).await?;
+    /// println!("{} {:?}", executed.payout.id, executed.payout.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_evm<S: EvmSigner + ?Sized>(
+        &self,
+        input: CreateQuoteInput,
+        signer: &S,
+        options: ExecutePayoutOptions,
+    ) -> Result<ExecutedPayout> {
+        self.execute(input, &EvmPayoutSigner::new(signer), options)
+            .await
+    }
+
+    /// Get the token authorization transaction a Stellar wallet must sign
+    /// before a payout
+    pub async fn authorize_stellar_token(
+        &self,
+        quote_id: &str,
+        sender_wallet_address: &str,
+    ) -> Result<AuthorizeStellarTokenResponse> {
+        let path = format!(
+            "/instances/{}/payouts/stellar/authorize",
+            self.client.instance_id()
+        );
+        let body = serde_json::json!({
+            "quote_id": quote_id,
+            "sender_wallet_address": sender_wallet_address,
+        });
         self.client.post(&path, body).await
     }
 
+    /// Get the transaction a Solana wallet must sign to delegate the payout
+    /// amount before a payout
+    pub async fn prepare_solana_delegation(
+        &self,
+        input: PrepareSolanaDelegationInput,
+    ) -> Result<PrepareSolanaDelegationResponse> {
+        let path = format!(
+            "/instances/{}/prepare-delegate-solana",
+            self.client.instance_id()
+        );
+        self.client.post(&path, input).await
+    }
+
     /// Create a Solana payout
     pub async fn create_solana(
         &self,
@@ -180,6 +662,8 @@ impl PayoutsResource {
             "sender_wallet_address": sender_wallet_address,
             "signed_transaction": signed_transaction,
         });
-        self.client.post(&path, body).await
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 }
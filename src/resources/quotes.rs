@@ -1,8 +1,12 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::{input_builder, request_options};
+use crate::money::Amount;
+use crate::options::RequestOptions;
 use crate::types::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkInfo {
@@ -28,7 +32,7 @@ pub struct CreateQuoteInput {
     pub bank_account_id: String,
     pub currency_type: CurrencyType,
     pub cover_fees: bool,
-    pub request_amount: f64,
+    pub request_amount: Amount,
     pub network: Network,
     pub token: Option<StablecoinToken>,
     pub description: Option<String>,
@@ -38,83 +42,145 @@ pub struct CreateQuoteInput {
     pub transaction_document_type: Option<TransactionDocumentType>,
 }
 
+input_builder! {
+    /// Builder for [`CreateQuoteInput`]
+    pub struct CreateQuoteInputBuilder => CreateQuoteInput {
+        required {
+            bank_account_id: String,
+            currency_type: CurrencyType,
+            cover_fees: bool,
+            request_amount: Amount,
+            network: Network,
+        }
+        optional {
+            token: StablecoinToken,
+            description: String,
+            partner_fee_id: String,
+            transaction_document_file: String,
+            transaction_document_id: String,
+            transaction_document_type: TransactionDocumentType,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateQuoteResponse {
     pub id: String,
     pub expires_at: i64,
-    pub commercial_quotation: f64,
-    pub blindpay_quotation: f64,
-    pub receiver_amount: f64,
-    pub sender_amount: f64,
-    pub partner_fee_amount: Option<f64>,
-    pub flat_fee: Option<f64>,
+    pub commercial_quotation: Amount,
+    pub blindpay_quotation: Amount,
+    pub receiver_amount: Amount,
+    pub sender_amount: Amount,
+    pub partner_fee_amount: Option<Amount>,
+    pub flat_fee: Option<Amount>,
     pub contract: Option<ContractInfo>,
-    pub receiver_local_amount: Option<f64>,
+    pub receiver_local_amount: Option<Amount>,
     pub description: Option<String>,
 }
 
+impl CreateQuoteResponse {
+    /// When the quote stops being accepted
+    pub fn expires_at_time(&self) -> SystemTime {
+        expiry_time(self.expires_at)
+    }
+
+    /// When the quote stops being accepted
+    #[cfg(feature = "chrono")]
+    pub fn expires_at_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        self.expires_at_time().into()
+    }
+
+    /// Whether the quote has expired and can no longer be used
+    pub fn is_expired(&self) -> bool {
+        self.time_remaining().is_zero()
+    }
+
+    /// Time left before the quote expires; zero once it has expired
+    pub fn time_remaining(&self) -> Duration {
+        self.expires_at_time()
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetFxRateInput {
     pub currency_type: CurrencyType,
     pub from: StablecoinToken,
     pub to: Currency,
-    pub request_amount: f64,
+    pub request_amount: Amount,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetFxRateResponse {
-    pub commercial_quotation: f64,
-    pub blindpay_quotation: f64,
-    pub result_amount: f64,
-    pub instance_flat_fee: Option<f64>,
-    pub instance_percentage_fee: f64,
+    pub commercial_quotation: Amount,
+    pub blindpay_quotation: Amount,
+    pub result_amount: Amount,
+    pub instance_flat_fee: Option<Amount>,
+    pub instance_percentage_fee: Amount,
+}
+
+/// Convert an `expires_at` epoch value to a point in time.
+///
+/// Values past 10^12 can only be milliseconds (seconds that large are
+/// 30,000 years away), anything smaller is read as seconds.
+fn expiry_time(expires_at: i64) -> SystemTime {
+    let expires_at = u64::try_from(expires_at).unwrap_or(0);
+    if expires_at >= 1_000_000_000_000 {
+        UNIX_EPOCH + Duration::from_millis(expires_at)
+    } else {
+        UNIX_EPOCH + Duration::from_secs(expires_at)
+    }
 }
 
 pub struct QuotesResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(QuotesResource);
+
 impl QuotesResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// Create a quote for a payout
     ///
     /// # Example
     /// ```no_run
-    /// # use blindpay::BlindPay;
+    /// # use blindpay::{Amount, BlindPay};
     /// # use blindpay::resources::quotes::CreateQuoteInput;
     /// # use blindpay::types::{CurrencyType, Network, StablecoinToken};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = BlindPay::new("api-key", "instance-id")?;
-    /// let input = CreateQuoteInput {
-    ///     bank_account_id: "ba_123".to_string(),
-    ///     currency_type: CurrencyType::Sender,
-    ///     cover_fees: true,
-    ///     request_amount: 1000.0,
-    ///     network: Network::Polygon,
-    ///     token: Some(StablecoinToken::USDC),
-    ///     description: None,
-    ///     partner_fee_id: None,
-    ///     transaction_document_file: None,
-    ///     transaction_document_id: None,
-    ///     transaction_document_type: None,
-    /// };
+    /// let input = CreateQuoteInput::builder()
+    ///     .bank_account_id("ba_123")
+    ///     .currency_type(CurrencyType::Sender)
+    ///     .request_amount(Amount::from(1000))
+    ///     .network(Network::Polygon)
+    ///     .token(StablecoinToken::USDC)
+    ///     .cover_fees(true)
+    ///     .build()?;
     /// let quote = client.quotes().create(input).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn create(&self, input: CreateQuoteInput) -> Result<CreateQuoteResponse> {
         let path = format!("/instances/{}/quotes", self.client.instance_id());
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Get FX rate for currency conversion
     ///
     /// # Example
     /// ```no_run
-    /// # use blindpay::BlindPay;
+    /// # use blindpay::{Amount, BlindPay};
     /// # use blindpay::resources::quotes::GetFxRateInput;
     /// # use blindpay::types::{CurrencyType, StablecoinToken, Currency};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -123,7 +189,7 @@ impl QuotesResource {
     ///     currency_type: CurrencyType::Sender,
     ///     from: StablecoinToken::USDC,
     ///     to: Currency::BRL,
-    ///     request_amount: 1000.0,
+    ///     request_amount: Amount::from(1000),
     /// };
     /// let rate = client.quotes().get_fx_rate(input).await?;
     /// # Ok(())
@@ -141,7 +207,7 @@ pub struct CreatePayinQuoteInput {
     pub blockchain_wallet_id: String,
     pub currency_type: CurrencyType,
     pub payment_method: PayinPaymentMethod,
-    pub request_amount: f64,
+    pub request_amount: Amount,
     pub token: StablecoinToken,
     pub is_otc: Option<bool>,
     pub cover_fees: bool,
@@ -153,46 +219,77 @@ pub struct CreatePayinQuoteInput {
 pub struct CreatePayinQuoteResponse {
     pub id: String,
     pub expires_at: i64,
-    pub commercial_quotation: f64,
-    pub blindpay_quotation: f64,
-    pub receiver_amount: f64,
-    pub sender_amount: f64,
-    pub partner_fee_amount: Option<f64>,
-    pub flat_fee: f64,
+    pub commercial_quotation: Amount,
+    pub blindpay_quotation: Amount,
+    pub receiver_amount: Amount,
+    pub sender_amount: Amount,
+    pub partner_fee_amount: Option<Amount>,
+    pub flat_fee: Amount,
     pub is_otc: Option<bool>,
 }
 
+impl CreatePayinQuoteResponse {
+    /// When the quote stops being accepted
+    pub fn expires_at_time(&self) -> SystemTime {
+        expiry_time(self.expires_at)
+    }
+
+    /// When the quote stops being accepted
+    #[cfg(feature = "chrono")]
+    pub fn expires_at_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        self.expires_at_time().into()
+    }
+
+    /// Whether the quote has expired and can no longer be used
+    pub fn is_expired(&self) -> bool {
+        self.time_remaining().is_zero()
+    }
+
+    /// Time left before the quote expires; zero once it has expired
+    pub fn time_remaining(&self) -> Duration {
+        self.expires_at_time()
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetPayinFxRateInput {
     pub currency_type: CurrencyType,
     pub from: Currency,
     pub to: Currency,
-    pub request_amount: f64,
+    pub request_amount: Amount,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetPayinFxRateResponse {
-    pub commercial_quotation: f64,
-    pub blindpay_quotation: f64,
-    pub result_amount: f64,
-    pub instance_flat_fee: f64,
-    pub instance_percentage_fee: f64,
+    pub commercial_quotation: Amount,
+    pub blindpay_quotation: Amount,
+    pub result_amount: Amount,
+    pub instance_flat_fee: Amount,
+    pub instance_percentage_fee: Amount,
 }
 
 pub struct PayinQuotesResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(PayinQuotesResource);
+
 impl PayinQuotesResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// Create a payin quote
     ///
     /// # Example
     /// ```no_run
-    /// # use blindpay::BlindPay;
+    /// # use blindpay::{Amount, BlindPay};
     /// # use blindpay::resources::quotes::CreatePayinQuoteInput;
     /// # use blindpay::types::{CurrencyType, PayinPaymentMethod, StablecoinToken};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -201,7 +298,7 @@ impl PayinQuotesResource {
     ///     blockchain_wallet_id: "bw_123".to_string(),
     ///     currency_type: CurrencyType::Sender,
     ///     payment_method: PayinPaymentMethod::Pix,
-    ///     request_amount: 5000.0,
+    ///     request_amount: Amount::from(5000),
     ///     token: StablecoinToken::USDC,
     ///     is_otc: None,
     ///     cover_fees: true,
@@ -214,7 +311,9 @@ impl PayinQuotesResource {
     /// ```
     pub async fn create(&self, input: CreatePayinQuoteInput) -> Result<CreatePayinQuoteResponse> {
         let path = format!("/instances/{}/payin-quotes", self.client.instance_id());
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Get FX rate for payin
@@ -223,3 +322,107 @@ impl PayinQuotesResource {
         self.client.post(&path, input).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote_expiring_at(expires_at: i64) -> CreateQuoteResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": "qu_123",
+            "expires_at": expires_at,
+            "commercial_quotation": 5.1,
+            "blindpay_quotation": 5.0,
+            "receiver_amount": 500.0,
+            "sender_amount": 100.0,
+            "partner_fee_amount": null,
+            "flat_fee": null,
+            "contract": null,
+            "receiver_local_amount": null,
+            "description": null
+        }))
+        .unwrap()
+    }
+
+    fn epoch_millis(time: SystemTime) -> i64 {
+        time.duration_since(UNIX_EPOCH).unwrap().as_millis() as i64
+    }
+
+    #[test]
+    fn test_quote_expiry_in_milliseconds() {
+        let quote = quote_expiring_at(epoch_millis(SystemTime::now()) + 60_000);
+        assert!(!quote.is_expired());
+        let remaining = quote.time_remaining();
+        assert!(remaining > Duration::from_secs(55) && remaining <= Duration::from_secs(60));
+
+        let expired = quote_expiring_at(epoch_millis(SystemTime::now()) - 1_000);
+        assert!(expired.is_expired());
+        assert_eq!(expired.time_remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_quote_expiry_in_seconds() {
+        let quote = quote_expiring_at(epoch_millis(SystemTime::now()) / 1000 + 120);
+        assert!(!quote.is_expired());
+        assert!(quote.time_remaining() > Duration::from_secs(110));
+        assert_eq!(
+            quote_expiring_at(1_700_000_000).expires_at_time(),
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_expires_at_datetime() {
+        let quote = quote_expiring_at(1_700_000_000_000);
+        assert_eq!(
+            quote.expires_at_datetime().to_rfc3339(),
+            "2023-11-14T22:13:20+00:00"
+        );
+    }
+
+    #[test]
+    fn test_quote_builder_defaults_optional_fields() {
+        let input = CreateQuoteInput::builder()
+            .bank_account_id("ba_123")
+            .currency_type(CurrencyType::Sender)
+            .cover_fees(false)
+            .request_amount(1000)
+            .network("polygon")
+            .build()
+            .unwrap();
+
+        assert_eq!(input.network, Network::Polygon);
+        assert_eq!(input.request_amount, Amount::from(1000));
+        assert!(!input.cover_fees);
+        assert!(input.token.is_none() && input.description.is_none());
+    }
+
+    #[test]
+    fn test_quote_builder_reports_missing_field() {
+        let err = CreateQuoteInput::builder()
+            .bank_account_id("ba_123")
+            .currency_type(CurrencyType::Sender)
+            .cover_fees(true)
+            .network(Network::Polygon)
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            crate::BlindPayError::MissingField("request_amount")
+        ));
+
+        let err = CreateQuoteInput::builder()
+            .bank_account_id("ba_123")
+            .currency_type(CurrencyType::Sender)
+            .request_amount(1000)
+            .network(Network::Polygon)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::BlindPayError::MissingField("cover_fees")
+        ));
+    }
+}
@@ -1,77 +1,91 @@
 use crate::client::BlindPay;
-use crate::error::Result;
+use crate::error::{BlindPayError, Result};
+use crate::macros::{api_enum, input_builder, request_options};
+use crate::options::RequestOptions;
+use crate::pagination::{paginate, Page, PaginateOptions, Paginated};
 use crate::types::*;
+use futures_util::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 // Re-export bank accounts
-use crate::resources::bank_accounts::BankAccountsResource;
 pub use crate::resources::bank_accounts;
+use crate::resources::bank_accounts::BankAccountsResource;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ProofOfAddressDocType {
-    UtilityBill,
-    BankStatement,
-    RentalAgreement,
-    TaxDocument,
-    GovernmentCorrespondence,
+api_enum! {
+    pub enum ProofOfAddressDocType {
+        UtilityBill => "UTILITY_BILL",
+        BankStatement => "BANK_STATEMENT",
+        RentalAgreement => "RENTAL_AGREEMENT",
+        TaxDocument => "TAX_DOCUMENT",
+        GovernmentCorrespondence => "GOVERNMENT_CORRESPONDENCE",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum PurposeOfTransactions {
-    BusinessTransactions,
-    CharitableDonations,
-    InvestmentPurposes,
-    PaymentsToFriendsOrFamilyAbroad,
-    PersonalOrLivingExpenses,
-    ProtectWealth,
-    PurchaseGoodAndServices,
-    ReceivePaymentForFreelancing,
-    ReceiveSalary,
-    Other,
+api_enum! {
+    pub enum PurposeOfTransactions {
+        BusinessTransactions => "business_transactions",
+        CharitableDonations => "charitable_donations",
+        InvestmentPurposes => "investment_purposes",
+        PaymentsToFriendsOrFamilyAbroad => "payments_to_friends_or_family_abroad",
+        PersonalOrLivingExpenses => "personal_or_living_expenses",
+        ProtectWealth => "protect_wealth",
+        PurchaseGoodAndServices => "purchase_good_and_services",
+        ReceivePaymentForFreelancing => "receive_payment_for_freelancing",
+        ReceiveSalary => "receive_salary",
+        Other => "other",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum SourceOfFundsDocType {
-    BusinessIncome,
-    GamblingProceeds,
-    Gifts,
-    GovernmentBenefits,
-    Inheritance,
-    InvestmentLoans,
-    PensionRetirement,
-    Salary,
-    SaleOfAssetsRealEstate,
-    Savings,
-    Esops,
-    InvestmentProceeds,
-    SomeoneElseFunds,
+api_enum! {
+    pub enum SourceOfFundsDocType {
+        BusinessIncome => "business_income",
+        GamblingProceeds => "gambling_proceeds",
+        Gifts => "gifts",
+        GovernmentBenefits => "government_benefits",
+        Inheritance => "inheritance",
+        InvestmentLoans => "investment_loans",
+        PensionRetirement => "pension_retirement",
+        Salary => "salary",
+        SaleOfAssetsRealEstate => "sale_of_assets_real_estate",
+        Savings => "savings",
+        Esops => "esops",
+        InvestmentProceeds => "investment_proceeds",
+        SomeoneElseFunds => "someone_else_funds",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum IdentificationDocument {
-    Passport,
-    IdCard,
-    Drivers,
+api_enum! {
+    pub enum IdentificationDocument {
+        Passport => "PASSPORT",
+        IdCard => "ID_CARD",
+        Drivers => "DRIVERS",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum KycType {
-    Light,
-    Standard,
-    Enhanced,
+api_enum! {
+    pub enum KycType {
+        Light => "light",
+        Standard => "standard",
+        Enhanced => "enhanced",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum OwnerRole {
-    BeneficialControlling,
-    BeneficialOwner,
-    ControllingPerson,
+api_enum! {
+    /// Where a receiver is in KYC/KYB review
+    pub enum KycStatus {
+        Verifying => "verifying",
+        Approved => "approved",
+        Rejected => "rejected",
+        Deprecated => "deprecated",
+    }
+}
+
+api_enum! {
+    pub enum OwnerRole {
+        BeneficialControlling => "beneficial_controlling",
+        BeneficialOwner => "beneficial_owner",
+        ControllingPerson => "controlling_person",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,7 +132,7 @@ pub struct Receiver {
     #[serde(rename = "type")]
     pub account_type: AccountClass,
     pub kyc_type: KycType,
-    pub kyc_status: String,
+    pub kyc_status: KycStatus,
     pub kyc_warnings: Option<Vec<KycWarning>>,
     pub email: String,
     pub tax_id: String,
@@ -161,11 +175,89 @@ pub struct Receiver {
     pub external_id: Option<String>,
     pub tos_id: Option<String>,
     pub is_fbo: Option<bool>,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
     pub limit: ReceiverLimits,
 }
 
+/// The individual or business specific part of a [`Receiver`], from [`Receiver::kind`]
+#[derive(Debug, Clone)]
+pub enum ReceiverKind {
+    Individual {
+        first_name: String,
+        last_name: String,
+        date_of_birth: Option<String>,
+        id_doc_country: Option<Country>,
+        id_doc_type: Option<IdentificationDocument>,
+        id_doc_front_file: Option<String>,
+        id_doc_back_file: Option<String>,
+    },
+    Business {
+        legal_name: String,
+        alternate_name: Option<String>,
+        formation_date: Option<String>,
+        website: Option<String>,
+        owners: Vec<Owner>,
+        incorporation_doc_file: Option<String>,
+        proof_of_ownership_doc_file: Option<String>,
+    },
+    /// An account class this version of the SDK does not know, or a receiver
+    /// missing the name fields of its class
+    Other { account_class: AccountClass },
+}
+
+impl Receiver {
+    /// The individual or business fields of this receiver as one typed value
+    ///
+    /// Enhanced KYC fields stay on the receiver itself.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::resources::receivers::{Receiver, ReceiverKind};
+    /// # fn example(receiver: Receiver) {
+    /// match receiver.kind() {
+    ///     ReceiverKind::Individual { first_name, last_name, .. } => {
+    ///         println!("{} {}", first_name, last_name)
+    ///     }
+    ///     ReceiverKind::Business { legal_name, owners, .. } => {
+    ///         println!("{} with {} owners", legal_name, owners.len())
+    ///     }
+    ///     ReceiverKind::Other { account_class } => println!("{}", account_class),
+    /// }
+    /// # }
+    /// ```
+    pub fn kind(&self) -> ReceiverKind {
+        self.try_kind().unwrap_or_else(|| ReceiverKind::Other {
+            account_class: self.account_type.clone(),
+        })
+    }
+
+    fn try_kind(&self) -> Option<ReceiverKind> {
+        let kind = match self.account_type {
+            AccountClass::Individual => ReceiverKind::Individual {
+                first_name: self.first_name.clone()?,
+                last_name: self.last_name.clone()?,
+                date_of_birth: self.date_of_birth.clone(),
+                id_doc_country: self.id_doc_country.clone(),
+                id_doc_type: self.id_doc_type.clone(),
+                id_doc_front_file: self.id_doc_front_file.clone(),
+                id_doc_back_file: self.id_doc_back_file.clone(),
+            },
+            AccountClass::Business => ReceiverKind::Business {
+                legal_name: self.legal_name.clone()?,
+                alternate_name: self.alternate_name.clone(),
+                formation_date: self.formation_date.clone(),
+                website: self.website.clone(),
+                owners: self.owners.clone().unwrap_or_default(),
+                incorporation_doc_file: self.incorporation_doc_file.clone(),
+                proof_of_ownership_doc_file: self.proof_of_ownership_doc_file.clone(),
+            },
+            AccountClass::Unknown(_) => return None,
+        };
+        Some(kind)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateIndividualWithStandardKycInput {
     pub external_id: Option<String>,
@@ -190,6 +282,36 @@ pub struct CreateIndividualWithStandardKycInput {
     pub tos_id: String,
 }
 
+input_builder! {
+    /// Builder for [`CreateIndividualWithStandardKycInput`]
+    pub struct CreateIndividualWithStandardKycInputBuilder => CreateIndividualWithStandardKycInput {
+        required {
+            address_line_1: String,
+            city: String,
+            country: Country,
+            date_of_birth: String,
+            email: String,
+            first_name: String,
+            id_doc_country: Country,
+            id_doc_front_file: String,
+            id_doc_type: IdentificationDocument,
+            last_name: String,
+            postal_code: String,
+            proof_of_address_doc_file: String,
+            proof_of_address_doc_type: ProofOfAddressDocType,
+            state_province_region: String,
+            tax_id: String,
+            tos_id: String,
+        }
+        optional {
+            external_id: String,
+            address_line_2: String,
+            phone_number: String,
+            id_doc_back_file: String,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateIndividualWithEnhancedKycInput {
     pub external_id: Option<String>,
@@ -242,6 +364,35 @@ pub struct CreateBusinessWithStandardKybInput {
     pub website: Option<String>,
 }
 
+input_builder! {
+    /// Builder for [`CreateBusinessWithStandardKybInput`]
+    pub struct CreateBusinessWithStandardKybInputBuilder => CreateBusinessWithStandardKybInput {
+        required {
+            address_line_1: String,
+            city: String,
+            country: Country,
+            email: String,
+            formation_date: String,
+            incorporation_doc_file: String,
+            legal_name: String,
+            owners: Vec<Owner>,
+            postal_code: String,
+            proof_of_address_doc_file: String,
+            proof_of_address_doc_type: ProofOfAddressDocType,
+            proof_of_ownership_doc_file: String,
+            state_province_region: String,
+            tax_id: String,
+            tos_id: String,
+        }
+        optional {
+            external_id: String,
+            address_line_2: String,
+            alternate_name: String,
+            website: String,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateReceiverInput {
     pub receiver_id: String,
@@ -303,23 +454,23 @@ pub struct LimitValues {
     pub monthly: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum LimitIncreaseRequestStatus {
-    InReview,
-    Approved,
-    Rejected,
+api_enum! {
+    pub enum LimitIncreaseRequestStatus {
+        InReview => "in_review",
+        Approved => "approved",
+        Rejected => "rejected",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum LimitIncreaseRequestSupportingDocumentType {
-    IndividualBankStatement,
-    IndividualTaxReturn,
-    IndividualProofOfIncome,
-    BusinessBankStatement,
-    BusinessFinancialStatements,
-    BusinessTaxReturn,
+api_enum! {
+    pub enum LimitIncreaseRequestSupportingDocumentType {
+        IndividualBankStatement => "individual_bank_statement",
+        IndividualTaxReturn => "individual_tax_return",
+        IndividualProofOfIncome => "individual_proof_of_income",
+        BusinessBankStatement => "business_bank_statement",
+        BusinessFinancialStatements => "business_financial_statements",
+        BusinessTaxReturn => "business_tax_return",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -332,8 +483,8 @@ pub struct LimitIncreaseRequest {
     pub per_transaction: u64,
     pub supporting_document_file: String,
     pub supporting_document_type: LimitIncreaseRequestSupportingDocumentType,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -351,29 +502,249 @@ pub struct RequestLimitIncreaseResponse {
     pub id: String,
 }
 
+/// A page of receivers from [`ReceiversResource::list`]
+///
+/// Also accepts a bare array of receivers, returned by instances without
+/// receiver pagination, as a single last page.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListReceiversResponse {
+    pub data: Vec<Receiver>,
+    pub pagination: PaginationMetadata,
+}
+
+impl<'de> Deserialize<'de> for ListReceiversResponse {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Shape {
+            Paginated {
+                data: Vec<Receiver>,
+                pagination: PaginationMetadata,
+            },
+            Bare(Vec<Receiver>),
+        }
+
+        Ok(match Shape::deserialize(deserializer)? {
+            Shape::Paginated { data, pagination } => Self { data, pagination },
+            Shape::Bare(data) => Self {
+                data,
+                pagination: PaginationMetadata {
+                    has_more: false,
+                    next_page: None,
+                    prev_page: None,
+                },
+            },
+        })
+    }
+}
+
+/// Filters and pagination for [`ReceiversResource::list`]
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::resources::receivers::{KycStatus, ListReceiversParams};
+/// # use blindpay::types::AccountClass;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let params = ListReceiversParams::new()
+///     .limit(50)
+///     .kyc_status(KycStatus::Approved)
+///     .account_class(AccountClass::Business)
+///     .created_after("2025-01-01T00:00:00Z");
+/// let receivers = client.receivers().list(Some(params)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListReceiversParams {
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kyc_status: Option<KycStatus>,
+    /// Only individual or only business receivers
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub account_class: Option<AccountClass>,
+    /// Only receivers created at or after this ISO 8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<Timestamp>,
+    /// Only receivers created before this ISO 8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<Timestamp>,
+}
+
+impl ListReceiversParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of receivers per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.pagination.limit = Some(limit);
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn kyc_status(mut self, kyc_status: KycStatus) -> Self {
+        self.kyc_status = Some(kyc_status);
+        self
+    }
+
+    pub fn account_class(mut self, account_class: AccountClass) -> Self {
+        self.account_class = Some(account_class);
+        self
+    }
+
+    pub fn created_after(mut self, created_after: impl Into<Timestamp>) -> Self {
+        self.created_after = Some(created_after.into());
+        self
+    }
+
+    pub fn created_before(mut self, created_before: impl Into<Timestamp>) -> Self {
+        self.created_before = Some(created_before.into());
+        self
+    }
+}
+
+impl From<PaginationParams> for ListReceiversParams {
+    fn from(pagination: PaginationParams) -> Self {
+        Self {
+            pagination,
+            ..Self::default()
+        }
+    }
+}
+
+impl Paginated for ListReceiversParams {
+    fn pagination_mut(&mut self) -> &mut PaginationParams {
+        &mut self.pagination
+    }
+}
+
 pub struct ReceiversResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(ReceiversResource);
+
 impl ReceiversResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
-    /// List all receivers
+    /// List receivers
     ///
     /// # Example
     /// ```no_run
     /// # use blindpay::BlindPay;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = BlindPay::new("api-key", "instance-id")?;
-    /// let receivers = client.receivers().list().await?;
+    /// let receivers = client.receivers().list(None).await?;
+    /// for receiver in receivers.data {
+    ///     println!("{}", receiver.id);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(&self) -> Result<Vec<Receiver>> {
+    pub async fn list(&self, params: Option<ListReceiversParams>) -> Result<ListReceiversResponse> {
         let path = format!("/instances/{}/receivers", self.client.instance_id());
-        self.client.get(&path).await
+        self.client
+            .get_with_query(&path, &params.unwrap_or_default())
+            .await
+    }
+
+    /// Stream every receiver, following pagination cursors automatically
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # use blindpay::pagination::PaginateOptions;
+    /// # use blindpay::resources::receivers::{KycStatus, ListReceiversParams};
+    /// # use futures_util::TryStreamExt;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// let params = ListReceiversParams::new().kyc_status(KycStatus::Verifying);
+    /// let mut receivers = Box::pin(client.receivers().list_stream(Some(params), PaginateOptions::new()));
+    /// while let Some(receiver) = receivers.try_next().await? {
+    ///     println!("{}", receiver.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_stream(
+        &self,
+        params: Option<ListReceiversParams>,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Receiver>> {
+        let client = self.client.clone();
+        paginate(params.unwrap_or_default(), options, move |params| {
+            let resource = ReceiversResource::new(client.clone());
+            async move {
+                let response = resource.list(Some(params)).await?;
+                Ok(Page::with_cursor(
+                    response.data,
+                    response.pagination.has_more,
+                    |receiver| &receiver.id,
+                ))
+            }
+        })
+    }
+
+    /// Collect every receiver into memory, following pagination cursors automatically
+    pub async fn list_all(
+        &self,
+        params: Option<ListReceiversParams>,
+        options: PaginateOptions,
+    ) -> Result<Vec<Receiver>> {
+        self.list_stream(params, options).try_collect().await
+    }
+
+    /// Find the receiver created with `external_id`, if any
+    ///
+    /// Fetches a single page filtered by `external_id`. Fails with
+    /// [`BlindPayError::FilterNotApplied`] if the API returns a receiver with
+    /// another external id, rather than walking every receiver.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::BlindPay;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BlindPay::new("api-key", "instance-id")?;
+    /// if let Some(receiver) = client.receivers().get_by_external_id("user_42").await? {
+    ///     println!("user_42 is {}", receiver.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_by_external_id(&self, external_id: &str) -> Result<Option<Receiver>> {
+        let params = ListReceiversParams::new().external_id(external_id).limit(1);
+        let page = self.list(Some(params)).await?;
+        match page.data.into_iter().next() {
+            Some(receiver) if receiver.external_id.as_deref() == Some(external_id) => {
+                Ok(Some(receiver))
+            }
+            Some(_) => Err(BlindPayError::FilterNotApplied("external_id")),
+            None => Ok(None),
+        }
     }
 
     /// Create an individual receiver with standard KYC
@@ -385,29 +756,24 @@ impl ReceiversResource {
     /// # use blindpay::types::Country;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = BlindPay::new("api-key", "instance-id")?;
-    /// let input = CreateIndividualWithStandardKycInput {
-    ///     email: "user@example.com".to_string(),
-    ///     first_name: "John".to_string(),
-    ///     last_name: "Doe".to_string(),
-    ///     // ... other fields
-    /// #   external_id: None,
-    /// #   address_line_1: "123 Main St".to_string(),
-    /// #   address_line_2: None,
-    /// #   city: "New York".to_string(),
-    /// #   country: Country::US,
-    /// #   date_of_birth: "1990-01-01".to_string(),
-    /// #   phone_number: None,
-    /// #   id_doc_country: Country::US,
-    /// #   id_doc_front_file: "file_url".to_string(),
-    /// #   id_doc_type: IdentificationDocument::Passport,
-    /// #   id_doc_back_file: None,
-    /// #   postal_code: "10001".to_string(),
-    /// #   proof_of_address_doc_file: "file_url".to_string(),
-    /// #   proof_of_address_doc_type: ProofOfAddressDocType::UtilityBill,
-    /// #   state_province_region: "NY".to_string(),
-    /// #   tax_id: "123456789".to_string(),
-    /// #   tos_id: "tos_123".to_string(),
-    /// };
+    /// let input = CreateIndividualWithStandardKycInput::builder()
+    ///     .email("user@example.com")
+    ///     .first_name("John")
+    ///     .last_name("Doe")
+    ///     .date_of_birth("1990-01-01")
+    ///     .tax_id("123456789")
+    ///     .address_line_1("123 Main St")
+    ///     .city("New York")
+    ///     .state_province_region("NY")
+    ///     .postal_code("10001")
+    ///     .country(Country::US)
+    ///     .id_doc_country(Country::US)
+    ///     .id_doc_type(IdentificationDocument::Passport)
+    ///     .id_doc_front_file("https://example.com/id.jpg")
+    ///     .proof_of_address_doc_type(ProofOfAddressDocType::UtilityBill)
+    ///     .proof_of_address_doc_file("https://example.com/bill.pdf")
+    ///     .tos_id("tos_123")
+    ///     .build()?;
     /// let receiver = client.receivers().create_individual_with_standard_kyc(input).await?;
     /// # Ok(())
     /// # }
@@ -420,7 +786,9 @@ impl ReceiversResource {
         let mut body = serde_json::to_value(input)?;
         body["kyc_type"] = serde_json::json!("standard");
         body["type"] = serde_json::json!("individual");
-        self.client.post(&path, body).await
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 
     /// Create an individual receiver with enhanced KYC
@@ -432,7 +800,9 @@ impl ReceiversResource {
         let mut body = serde_json::to_value(input)?;
         body["kyc_type"] = serde_json::json!("enhanced");
         body["type"] = serde_json::json!("individual");
-        self.client.post(&path, body).await
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 
     /// Create a business receiver with standard KYB
@@ -444,7 +814,9 @@ impl ReceiversResource {
         let mut body = serde_json::to_value(input)?;
         body["kyc_type"] = serde_json::json!("standard");
         body["type"] = serde_json::json!("business");
-        self.client.post(&path, body).await
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 
     /// Get a receiver by ID
@@ -542,7 +914,9 @@ impl ReceiversResource {
             self.client.instance_id(),
             receiver_id
         );
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Access bank accounts sub-resource
@@ -559,4 +933,244 @@ impl ReceiversResource {
     pub fn bank_accounts(&self) -> BankAccountsResource {
         BankAccountsResource::new(self.client.clone())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receiver(fields: serde_json::Value) -> Receiver {
+        let mut json = serde_json::json!({
+            "id": "re_123",
+            "is_tos_accepted": true,
+            "kyc_type": "standard",
+            "kyc_status": "approved",
+            "kyc_warnings": null,
+            "email": "jane@example.com",
+            "tax_id": "123456789",
+            "address_line_1": "1 Main St",
+            "city": "New York",
+            "state_province_region": "NY",
+            "country": "US",
+            "postal_code": "10001",
+            "proof_of_address_doc_type": "UTILITY_BILL",
+            "proof_of_address_doc_file": "https://example.com/proof.pdf",
+            "aiprise_validation_key": "key",
+            "instance_id": "in_123",
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z",
+            "limit": {"per_transaction": 1000, "daily": 5000, "monthly": 20000}
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_kind_distinguishes_individuals_and_businesses() {
+        let individual = receiver(serde_json::json!({
+            "type": "individual",
+            "first_name": "Jane",
+            "last_name": "Doe"
+        }));
+        assert_eq!(individual.kyc_status, KycStatus::Approved);
+        assert!(matches!(
+            individual.kind(),
+            ReceiverKind::Individual { first_name, last_name, .. }
+                if first_name == "Jane" && last_name == "Doe"
+        ));
+
+        let business = receiver(serde_json::json!({
+            "type": "business",
+            "legal_name": "Acme Inc",
+            "kyc_status": "manual_review"
+        }));
+        assert_eq!(
+            business.kyc_status,
+            KycStatus::Unknown("manual_review".to_string())
+        );
+        assert!(matches!(
+            business.kind(),
+            ReceiverKind::Business { legal_name, owners, .. }
+                if legal_name == "Acme Inc" && owners.is_empty()
+        ));
+
+        // An individual without a name falls back to Other
+        let nameless = receiver(serde_json::json!({"type": "individual"}));
+        assert!(matches!(
+            nameless.kind(),
+            ReceiverKind::Other {
+                account_class: AccountClass::Individual
+            }
+        ));
+    }
+
+    #[test]
+    fn test_list_response_accepts_bare_array() {
+        let bare = serde_json::json!([serde_json::to_value(receiver(
+            serde_json::json!({"type": "individual"})
+        ))
+        .unwrap()]);
+        let response: ListReceiversResponse = serde_json::from_value(bare).unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert!(!response.pagination.has_more);
+    }
+
+    fn receiver_json(id: &str, external_id: &str) -> serde_json::Value {
+        serde_json::to_value(receiver(serde_json::json!({
+            "id": id,
+            "type": "business",
+            "legal_name": "Acme Inc",
+            "external_id": external_id
+        })))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_stream_sends_filters_and_follows_cursors() {
+        let mut server = mockito::Server::new_async().await;
+        let filters = || {
+            vec![
+                mockito::Matcher::UrlEncoded("kyc_status".into(), "approved".into()),
+                mockito::Matcher::UrlEncoded("type".into(), "business".into()),
+            ]
+        };
+        let first = server
+            .mock("GET", "/instances/in_123/receivers")
+            .match_query(mockito::Matcher::AllOf(filters()))
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "data": [receiver_json("re_1", "a")],
+                        "pagination": {"has_more": true, "next_page": null, "prev_page": null}
+                    },
+                    "error": null
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let mut second_filters = filters();
+        second_filters.push(mockito::Matcher::UrlEncoded(
+            "starting_after".into(),
+            "re_1".into(),
+        ));
+        let second = server
+            .mock("GET", "/instances/in_123/receivers")
+            .match_query(mockito::Matcher::AllOf(second_filters))
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "data": [receiver_json("re_2", "b")],
+                        "pagination": {"has_more": false, "next_page": null, "prev_page": null}
+                    },
+                    "error": null
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = BlindPay::builder()
+            .api_key("test-api-key")
+            .instance_id("in_123")
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let params = ListReceiversParams::new()
+            .kyc_status(KycStatus::Approved)
+            .account_class(AccountClass::Business);
+
+        let receivers = client
+            .receivers()
+            .list_all(Some(params), PaginateOptions::new())
+            .await
+            .unwrap();
+        let ids: Vec<_> = receivers.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["re_1", "re_2"]);
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_by_external_id_fetches_one_page() {
+        let mut server = mockito::Server::new_async().await;
+        let found_mock = server
+            .mock("GET", "/instances/in_123/receivers")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("external_id".into(), "user_42".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "1".into()),
+            ]))
+            .with_body(
+                serde_json::json!({
+                    "data": [receiver_json("re_2", "user_42")],
+                    "error": null
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let empty = server
+            .mock("GET", "/instances/in_123/receivers")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "external_id".into(),
+                "user_7".into(),
+            ))
+            .with_body(r#"{"data":[],"error":null}"#)
+            .create_async()
+            .await;
+        let unfiltered = server
+            .mock("GET", "/instances/in_123/receivers")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "external_id".into(),
+                "user_4".into(),
+            ))
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "data": [receiver_json("re_1", "user_1")],
+                        "pagination": { "has_more": true, "next_page": null, "prev_page": null }
+                    },
+                    "error": null
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = BlindPay::builder()
+            .api_key("test-api-key")
+            .instance_id("in_123")
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let found = client
+            .receivers()
+            .get_by_external_id("user_42")
+            .await
+            .unwrap();
+        assert_eq!(found.map(|r| r.id), Some("re_2".to_string()));
+        let missing = client
+            .receivers()
+            .get_by_external_id("user_7")
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+        let err = client
+            .receivers()
+            .get_by_external_id("user_4")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BlindPayError::FilterNotApplied("external_id")
+        ));
+        found_mock.assert_async().await;
+        empty.assert_async().await;
+        unfiltered.assert_async().await;
+    }
+}
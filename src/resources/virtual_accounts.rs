@@ -1,13 +1,15 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::{api_enum, request_options};
+use crate::options::RequestOptions;
 use crate::types::{Network, StablecoinToken};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum BankingPartner {
-    Jpmorgan,
-    Citi,
+api_enum! {
+    pub enum BankingPartner {
+        Jpmorgan => "jpmorgan",
+        Citi => "citi",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,11 +78,17 @@ pub struct UpdateVirtualAccountInput {
 
 pub struct VirtualAccountsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(VirtualAccountsResource);
+
 impl VirtualAccountsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List virtual accounts for a receiver
@@ -150,7 +158,9 @@ impl VirtualAccountsResource {
             self.client.instance_id(),
             receiver_id
         );
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Update a virtual account
@@ -1,6 +1,10 @@
 use crate::client::BlindPay;
 use crate::error::Result;
-use crate::types::Network;
+use crate::macros::request_options;
+use crate::options::RequestOptions;
+use crate::pagination::{paginate, Page, PaginateOptions};
+use crate::types::{Network, PaginationParams};
+use futures_util::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,11 +41,17 @@ pub struct GetWalletMessageResponse {
 
 pub struct BlockchainWalletsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(BlockchainWalletsResource);
+
 impl BlockchainWalletsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List blockchain wallets for a receiver
@@ -54,6 +64,33 @@ impl BlockchainWalletsResource {
         self.client.get(&path).await
     }
 
+    /// Stream every blockchain wallet of a receiver
+    ///
+    /// The wallets endpoint returns all wallets in one response, so
+    /// `page_size` has no effect; `max_items` still limits the output.
+    pub fn list_stream(
+        &self,
+        receiver_id: &str,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<BlockchainWallet>> {
+        let client = self.client.clone();
+        let receiver_id = receiver_id.to_string();
+        paginate(PaginationParams::default(), options, move |_| {
+            let resource = BlockchainWalletsResource::new(client.clone());
+            let receiver_id = receiver_id.clone();
+            async move { Ok(Page::single(resource.list(&receiver_id).await?)) }
+        })
+    }
+
+    /// Collect every blockchain wallet of a receiver into memory
+    pub async fn list_all(
+        &self,
+        receiver_id: &str,
+        options: PaginateOptions,
+    ) -> Result<Vec<BlockchainWallet>> {
+        self.list_stream(receiver_id, options).try_collect().await
+    }
+
     /// Create a blockchain wallet with address (account abstraction)
     pub async fn create_with_address(
         &self,
@@ -65,11 +102,13 @@ impl BlockchainWalletsResource {
             self.client.instance_id(),
             receiver_id
         );
-        
+
         let mut body = serde_json::to_value(input)?;
         body["is_account_abstraction"] = serde_json::json!(true);
-        
-        self.client.post(&path, body).await
+
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 
     /// Create a blockchain wallet with signature hash
@@ -83,11 +122,13 @@ impl BlockchainWalletsResource {
             self.client.instance_id(),
             receiver_id
         );
-        
+
         let mut body = serde_json::to_value(input)?;
         body["is_account_abstraction"] = serde_json::json!(false);
-        
-        self.client.post(&path, body).await
+
+        self.client
+            .post_with_options(&path, body, &self.options)
+            .await
     }
 
     /// Get wallet sign message
@@ -1,7 +1,4 @@
 use crate::client::BlindPay;
-use crate::error::Result;
-use crate::types::Network;
-use serde::{Deserialize, Serialize};
 
 pub mod blockchain;
 pub mod offramp;
@@ -1,5 +1,10 @@
 use crate::client::BlindPay;
 use crate::error::Result;
+use crate::macros::request_options;
+use crate::options::RequestOptions;
+use crate::pagination::{paginate, Page, PaginateOptions};
+use crate::types::{PaginationParams, Timestamp};
+use futures_util::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,8 +16,8 @@ pub struct OfframpWallet {
     pub bank_account_id: String,
     pub network: String,
     pub address: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,11 +30,17 @@ pub struct CreateOfframpWalletInput {
 
 pub struct OfframpWalletsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(OfframpWalletsResource);
+
 impl OfframpWalletsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List offramp wallets
@@ -47,6 +58,42 @@ impl OfframpWalletsResource {
         self.client.get(&path).await
     }
 
+    /// Stream every offramp wallet of a bank account
+    ///
+    /// The offramp wallets endpoint returns all wallets in one response, so
+    /// `page_size` has no effect; `max_items` still limits the output.
+    pub fn list_stream(
+        &self,
+        receiver_id: &str,
+        bank_account_id: &str,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<OfframpWallet>> {
+        let client = self.client.clone();
+        let receiver_id = receiver_id.to_string();
+        let bank_account_id = bank_account_id.to_string();
+        paginate(PaginationParams::default(), options, move |_| {
+            let resource = OfframpWalletsResource::new(client.clone());
+            let receiver_id = receiver_id.clone();
+            let bank_account_id = bank_account_id.clone();
+            async move {
+                let wallets = resource.list(&receiver_id, &bank_account_id).await?;
+                Ok(Page::single(wallets))
+            }
+        })
+    }
+
+    /// Collect every offramp wallet of a bank account into memory
+    pub async fn list_all(
+        &self,
+        receiver_id: &str,
+        bank_account_id: &str,
+        options: PaginateOptions,
+    ) -> Result<Vec<OfframpWallet>> {
+        self.list_stream(receiver_id, bank_account_id, options)
+            .try_collect()
+            .await
+    }
+
     /// Create an offramp wallet
     pub async fn create(&self, input: CreateOfframpWalletInput) -> Result<OfframpWallet> {
         let receiver_id = input.receiver_id.clone();
@@ -57,7 +104,9 @@ impl OfframpWalletsResource {
             receiver_id,
             bank_account_id
         );
-        self.client.post(&path, input).await
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Get an offramp wallet
@@ -1,36 +1,43 @@
 use crate::client::BlindPay;
-use crate::error::Result;
+use crate::error::{BlindPayError, Result};
+use crate::macros::{api_enum, request_options};
+use crate::options::RequestOptions;
+use crate::resources::bank_accounts::BankAccount;
+use crate::resources::payins::Payin;
+use crate::resources::payouts::Payout;
+use crate::resources::receivers::Receiver;
+use crate::resources::wallets::blockchain::BlockchainWallet;
+use crate::types::Timestamp;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum WebhookEvent {
-    #[serde(rename = "receiver.new")]
-    ReceiverNew,
-    #[serde(rename = "receiver.update")]
-    ReceiverUpdate,
-    #[serde(rename = "bankAccount.new")]
-    BankAccountNew,
-    #[serde(rename = "payout.new")]
-    PayoutNew,
-    #[serde(rename = "payout.update")]
-    PayoutUpdate,
-    #[serde(rename = "payout.complete")]
-    PayoutComplete,
-    #[serde(rename = "payout.partnerFee")]
-    PayoutPartnerFee,
-    #[serde(rename = "blockchainWallet.new")]
-    BlockchainWalletNew,
-    #[serde(rename = "payin.new")]
-    PayinNew,
-    #[serde(rename = "payin.update")]
-    PayinUpdate,
-    #[serde(rename = "payin.complete")]
-    PayinComplete,
-    #[serde(rename = "payin.partnerFee")]
-    PayinPartnerFee,
-    #[serde(rename = "tos.accept")]
-    TosAccept,
+/// Default maximum age (and clock skew) accepted for a webhook delivery
+pub const DEFAULT_WEBHOOK_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+const SECRET_PREFIX: &str = "whsec_";
+const SIGNATURE_VERSION: &str = "v1";
+
+api_enum! {
+    pub enum WebhookEvent {
+        ReceiverNew => "receiver.new",
+        ReceiverUpdate => "receiver.update",
+        BankAccountNew => "bankAccount.new",
+        PayoutNew => "payout.new",
+        PayoutUpdate => "payout.update",
+        PayoutComplete => "payout.complete",
+        PayoutPartnerFee => "payout.partnerFee",
+        BlockchainWalletNew => "blockchainWallet.new",
+        PayinNew => "payin.new",
+        PayinUpdate => "payin.update",
+        PayinComplete => "payin.complete",
+        PayinPartnerFee => "payin.partnerFee",
+        TosAccept => "tos.accept",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,10 +45,10 @@ pub struct WebhookEndpoint {
     pub id: String,
     pub url: String,
     pub events: Vec<WebhookEvent>,
-    pub last_event_at: String,
+    pub last_event_at: Timestamp,
     pub instance_id: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,13 +72,277 @@ pub struct GetPortalAccessUrlResponse {
     pub url: String,
 }
 
+/// Signature headers sent with every webhook delivery
+#[derive(Debug, Clone)]
+pub struct WebhookHeaders {
+    pub id: String,
+    pub timestamp: String,
+    pub signature: String,
+}
+
+impl WebhookHeaders {
+    pub fn new(
+        id: impl Into<String>,
+        timestamp: impl Into<String>,
+        signature: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            timestamp: timestamp.into(),
+            signature: signature.into(),
+        }
+    }
+
+    /// Extract the signature headers from an incoming request.
+    ///
+    /// Both the `svix-*` and the `webhook-*` header names are accepted.
+    pub fn from_header_map(headers: &HeaderMap) -> Result<Self> {
+        let get = |name: &str| -> Result<String> {
+            [format!("svix-{}", name), format!("webhook-{}", name)]
+                .iter()
+                .find_map(|key| headers.get(key.as_str()))
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    BlindPayError::WebhookVerificationFailed(format!("missing {} header", name))
+                })
+        };
+
+        Ok(Self {
+            id: get("id")?,
+            timestamp: get("timestamp")?,
+            signature: get("signature")?,
+        })
+    }
+}
+
+/// A parsed webhook delivery, keyed by its [`WebhookEvent`]
+#[derive(Debug, Clone)]
+pub enum WebhookPayload {
+    ReceiverNew(Receiver),
+    ReceiverUpdate(Receiver),
+    BankAccountNew(BankAccount),
+    PayoutNew(Payout),
+    PayoutUpdate(Payout),
+    PayoutComplete(Payout),
+    PayoutPartnerFee(Payout),
+    BlockchainWalletNew(BlockchainWallet),
+    PayinNew(Payin),
+    PayinUpdate(Payin),
+    PayinComplete(Payin),
+    PayinPartnerFee(Payin),
+    TosAccept(serde_json::Value),
+    /// An event this version of the SDK does not know about, with its raw body
+    Unknown {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl WebhookPayload {
+    /// Parse a webhook body without verifying its signature.
+    ///
+    /// Prefer [`verify`] for anything received over the network.
+    pub fn from_slice(payload: &[u8]) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            webhook_event: Option<WebhookEvent>,
+        }
+
+        let event = serde_json::from_slice::<Envelope>(payload)?
+            .webhook_event
+            .ok_or_else(|| {
+                BlindPayError::WebhookVerificationFailed(
+                    "payload has no webhook_event field".to_string(),
+                )
+            })?;
+        // Parsed from the body itself rather than a `Value`, so amounts stay exact
+        Ok(match event {
+            WebhookEvent::ReceiverNew => Self::ReceiverNew(serde_json::from_slice(payload)?),
+            WebhookEvent::ReceiverUpdate => Self::ReceiverUpdate(serde_json::from_slice(payload)?),
+            WebhookEvent::BankAccountNew => Self::BankAccountNew(serde_json::from_slice(payload)?),
+            WebhookEvent::PayoutNew => Self::PayoutNew(serde_json::from_slice(payload)?),
+            WebhookEvent::PayoutUpdate => Self::PayoutUpdate(serde_json::from_slice(payload)?),
+            WebhookEvent::PayoutComplete => Self::PayoutComplete(serde_json::from_slice(payload)?),
+            WebhookEvent::PayoutPartnerFee => {
+                Self::PayoutPartnerFee(serde_json::from_slice(payload)?)
+            }
+            WebhookEvent::BlockchainWalletNew => {
+                Self::BlockchainWalletNew(serde_json::from_slice(payload)?)
+            }
+            WebhookEvent::PayinNew => Self::PayinNew(serde_json::from_slice(payload)?),
+            WebhookEvent::PayinUpdate => Self::PayinUpdate(serde_json::from_slice(payload)?),
+            WebhookEvent::PayinComplete => Self::PayinComplete(serde_json::from_slice(payload)?),
+            WebhookEvent::PayinPartnerFee => {
+                Self::PayinPartnerFee(serde_json::from_slice(payload)?)
+            }
+            WebhookEvent::TosAccept => Self::TosAccept(serde_json::from_slice(payload)?),
+            WebhookEvent::Unknown(event) => Self::Unknown {
+                event,
+                payload: serde_json::from_slice(payload)?,
+            },
+        })
+    }
+
+    /// The event this payload was delivered for
+    pub fn event(&self) -> WebhookEvent {
+        match self {
+            Self::ReceiverNew(_) => WebhookEvent::ReceiverNew,
+            Self::ReceiverUpdate(_) => WebhookEvent::ReceiverUpdate,
+            Self::BankAccountNew(_) => WebhookEvent::BankAccountNew,
+            Self::PayoutNew(_) => WebhookEvent::PayoutNew,
+            Self::PayoutUpdate(_) => WebhookEvent::PayoutUpdate,
+            Self::PayoutComplete(_) => WebhookEvent::PayoutComplete,
+            Self::PayoutPartnerFee(_) => WebhookEvent::PayoutPartnerFee,
+            Self::BlockchainWalletNew(_) => WebhookEvent::BlockchainWalletNew,
+            Self::PayinNew(_) => WebhookEvent::PayinNew,
+            Self::PayinUpdate(_) => WebhookEvent::PayinUpdate,
+            Self::PayinComplete(_) => WebhookEvent::PayinComplete,
+            Self::PayinPartnerFee(_) => WebhookEvent::PayinPartnerFee,
+            Self::TosAccept(_) => WebhookEvent::TosAccept,
+            Self::Unknown { event, .. } => WebhookEvent::Unknown(event.clone()),
+        }
+    }
+}
+
+/// Verifies webhook deliveries against an endpoint secret
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    key: Vec<u8>,
+    tolerance: Duration,
+}
+
+impl WebhookVerifier {
+    /// Create a verifier from the secret returned by
+    /// [`WebhookEndpointsResource::get_secret`]
+    pub fn new(secret: &str) -> Result<Self> {
+        let encoded = secret.strip_prefix(SECRET_PREFIX).unwrap_or(secret);
+        let key = BASE64.decode(encoded).map_err(|_| {
+            BlindPayError::InvalidConfiguration("webhook secret is not valid base64".to_string())
+        })?;
+
+        Ok(Self {
+            key,
+            tolerance: DEFAULT_WEBHOOK_TOLERANCE,
+        })
+    }
+
+    /// Override the accepted timestamp tolerance
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Verify a delivery and parse its payload
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use blindpay::webhooks::{WebhookHeaders, WebhookPayload, WebhookVerifier};
+    /// # fn example(body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    /// let verifier = WebhookVerifier::new("whsec_...")?;
+    /// let headers = WebhookHeaders::new("msg_123", "1700000000", "v1,...");
+    /// match verifier.verify(body, &headers)? {
+    ///     WebhookPayload::PayoutComplete(payout) => println!("Payout {} done", payout.id),
+    ///     other => println!("Received {:?}", other.event()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify(&self, payload: &[u8], headers: &WebhookHeaders) -> Result<WebhookPayload> {
+        self.verify_signature(payload, headers)?;
+        WebhookPayload::from_slice(payload)
+    }
+
+    /// Verify the signature and timestamp of a delivery without parsing it
+    pub fn verify_signature(&self, payload: &[u8], headers: &WebhookHeaders) -> Result<()> {
+        let timestamp: i64 = headers.timestamp.trim().parse().map_err(|_| {
+            BlindPayError::WebhookVerificationFailed("invalid timestamp header".to_string())
+        })?;
+
+        let now = unix_now();
+        if now.abs_diff(timestamp) > self.tolerance.as_secs() {
+            let reason = if timestamp < now {
+                "timestamp is too old"
+            } else {
+                "timestamp is too new"
+            };
+            return Err(BlindPayError::WebhookVerificationFailed(reason.to_string()));
+        }
+
+        let mac = self.mac(&headers.id, timestamp, payload);
+        let matched = headers
+            .signature
+            .split_whitespace()
+            .filter_map(|entry| entry.split_once(','))
+            .filter(|(version, _)| *version == SIGNATURE_VERSION)
+            .filter_map(|(_, signature)| BASE64.decode(signature).ok())
+            .any(|signature| mac.clone().verify_slice(&signature).is_ok());
+
+        if matched {
+            Ok(())
+        } else {
+            Err(BlindPayError::WebhookVerificationFailed(
+                "no matching signature found".to_string(),
+            ))
+        }
+    }
+
+    /// Produce the signature header value for a payload.
+    ///
+    /// Useful for generating deliveries in tests.
+    pub fn sign(&self, msg_id: &str, timestamp: i64, payload: &[u8]) -> String {
+        let signature = self.mac(msg_id, timestamp, payload).finalize().into_bytes();
+        format!("{},{}", SIGNATURE_VERSION, BASE64.encode(signature))
+    }
+
+    fn mac(&self, msg_id: &str, timestamp: i64, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(msg_id.as_bytes());
+        mac.update(b".");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        mac
+    }
+}
+
+/// Verify a webhook delivery with the default tolerance and parse its payload
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::webhooks::{self, WebhookHeaders};
+/// # fn example(body: &[u8], secret: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let headers = WebhookHeaders::new("msg_123", "1700000000", "v1,...");
+/// let payload = webhooks::verify(body, &headers, secret)?;
+/// println!("Received {:?}", payload.event());
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify(payload: &[u8], headers: &WebhookHeaders, secret: &str) -> Result<WebhookPayload> {
+    WebhookVerifier::new(secret)?.verify(payload, headers)
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default()
+}
+
 pub struct WebhookEndpointsResource {
     client: BlindPay,
+    options: RequestOptions,
 }
 
+request_options!(WebhookEndpointsResource);
+
 impl WebhookEndpointsResource {
     pub(crate) fn new(client: BlindPay) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
     }
 
     /// List webhook endpoints
@@ -86,10 +357,7 @@ impl WebhookEndpointsResource {
     /// # }
     /// ```
     pub async fn list(&self) -> Result<Vec<WebhookEndpoint>> {
-        let path = format!(
-            "/instances/{}/webhook-endpoints",
-            self.client.instance_id()
-        );
+        let path = format!("/instances/{}/webhook-endpoints", self.client.instance_id());
         self.client.get(&path).await
     }
 
@@ -113,11 +381,10 @@ impl WebhookEndpointsResource {
         &self,
         input: CreateWebhookEndpointInput,
     ) -> Result<CreateWebhookEndpointResponse> {
-        let path = format!(
-            "/instances/{}/webhook-endpoints",
-            self.client.instance_id()
-        );
-        self.client.post(&path, input).await
+        let path = format!("/instances/{}/webhook-endpoints", self.client.instance_id());
+        self.client
+            .post_with_options(&path, input, &self.options)
+            .await
     }
 
     /// Delete a webhook endpoint
@@ -160,3 +427,147 @@ impl WebhookEndpointsResource {
         self.client.get(&path).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Network;
+
+    const SECRET: &str = "whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw";
+
+    fn wallet_payload() -> Vec<u8> {
+        serde_json::json!({
+            "webhook_event": "blockchainWallet.new",
+            "id": "bw_123",
+            "name": "Main wallet",
+            "network": "polygon",
+            "address": "0x123",
+            "signature_tx_hash": null,
+            "is_account_abstraction": true,
+            "receiver_id": "re_123"
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn signed_headers(
+        verifier: &WebhookVerifier,
+        timestamp: i64,
+        payload: &[u8],
+    ) -> WebhookHeaders {
+        let signature = verifier.sign("msg_123", timestamp, payload);
+        WebhookHeaders::new("msg_123", timestamp.to_string(), signature)
+    }
+
+    #[test]
+    fn test_verify_valid_signature() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let payload = wallet_payload();
+        let headers = signed_headers(&verifier, unix_now(), &payload);
+
+        let parsed = verify(&payload, &headers, SECRET).unwrap();
+        assert_eq!(parsed.event(), WebhookEvent::BlockchainWalletNew);
+        assert!(matches!(parsed, WebhookPayload::BlockchainWalletNew(w) if w.id == "bw_123"));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_payload() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let payload = wallet_payload();
+        let headers = signed_headers(&verifier, unix_now(), &payload);
+
+        let tampered = String::from_utf8(payload)
+            .unwrap()
+            .replace("bw_123", "bw_999");
+        let result = verifier.verify(tampered.as_bytes(), &headers);
+        assert!(matches!(
+            result,
+            Err(BlindPayError::WebhookVerificationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_stale_timestamp() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let payload = wallet_payload();
+        let headers = signed_headers(&verifier, unix_now() - 600, &payload);
+
+        let result = verifier.verify(&payload, &headers);
+        assert!(matches!(
+            result,
+            Err(BlindPayError::WebhookVerificationFailed(_))
+        ));
+
+        let lenient = verifier.with_tolerance(Duration::from_secs(900));
+        assert!(lenient.verify(&payload, &headers).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_extreme_timestamps() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let lenient = verifier.clone().with_tolerance(Duration::MAX);
+        let payload = wallet_payload();
+
+        for timestamp in [i64::MIN, i64::MAX] {
+            let headers = signed_headers(&verifier, timestamp, &payload);
+            assert!(matches!(
+                verifier.verify(&payload, &headers),
+                Err(BlindPayError::WebhookVerificationFailed(_))
+            ));
+            assert!(lenient.verify(&payload, &headers).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_verify_accepts_any_matching_signature() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let payload = wallet_payload();
+        let mut headers = signed_headers(&verifier, unix_now(), &payload);
+        headers.signature = format!("v1,aW52YWxpZA== {}", headers.signature);
+
+        assert!(verifier.verify_signature(&payload, &headers).is_ok());
+    }
+
+    #[test]
+    fn test_headers_from_header_map() {
+        let mut map = HeaderMap::new();
+        map.insert("svix-id", "msg_123".parse().unwrap());
+        map.insert("svix-timestamp", "1700000000".parse().unwrap());
+        map.insert("webhook-signature", "v1,abc".parse().unwrap());
+
+        let headers = WebhookHeaders::from_header_map(&map).unwrap();
+        assert_eq!(headers.id, "msg_123");
+        assert_eq!(headers.timestamp, "1700000000");
+        assert_eq!(headers.signature, "v1,abc");
+
+        map.remove("svix-id");
+        assert!(WebhookHeaders::from_header_map(&map).is_err());
+    }
+
+    #[test]
+    fn test_unknown_event_is_preserved() {
+        let payload = br#"{"webhook_event":"payout.cancelled","id":"pa_123"}"#;
+        let parsed = WebhookPayload::from_slice(payload).unwrap();
+
+        assert_eq!(
+            parsed.event(),
+            WebhookEvent::Unknown("payout.cancelled".to_string())
+        );
+        assert!(matches!(
+            parsed,
+            WebhookPayload::Unknown { ref payload, .. } if payload["id"] == "pa_123"
+        ));
+    }
+
+    #[test]
+    fn test_known_fields_tolerate_new_enum_values() {
+        let mut payload: serde_json::Value = serde_json::from_slice(&wallet_payload()).unwrap();
+        payload["network"] = "zksync".into();
+
+        let parsed = WebhookPayload::from_slice(payload.to_string().as_bytes()).unwrap();
+        let WebhookPayload::BlockchainWalletNew(wallet) = parsed else {
+            panic!("expected a blockchain wallet payload");
+        };
+        assert_eq!(wallet.network, Network::Unknown("zksync".to_string()));
+    }
+}
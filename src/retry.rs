@@ -0,0 +1,318 @@
+use crate::error::{BlindPayError, Result};
+use crate::middleware::{Middleware, Next};
+use crate::options::IDEMPOTENCY_KEY_HEADER;
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, Request, Response, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+/// Controls how failed requests are retried
+///
+/// Idempotent requests (`GET`, `PUT`, `DELETE`) are retried whenever the
+/// failure is retryable. `POST` and `PATCH` requests are only retried when
+/// they carry an `Idempotency-Key` header, so a retry can never move money
+/// twice.
+///
+/// The policy set with [`BlindPayBuilder::retry_policy`](crate::BlindPayBuilder::retry_policy)
+/// runs after the registered [middlewares](crate::middleware). A policy is
+/// also a [`Middleware`] itself and can be registered at another position.
+///
+/// # Example
+/// ```no_run
+/// use blindpay::{BlindPay, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let client = BlindPay::new("api-key", "instance-id")
+///     .unwrap()
+///     .with_retry_policy(
+///         RetryPolicy::default()
+///             .max_attempts(5)
+///             .base_delay(Duration::from_millis(250)),
+///     );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retryable_statuses: Vec<StatusCode>,
+    retry_on_timeout: bool,
+    retry_on_connect_error: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts with exponential backoff starting at 500ms, retrying
+    /// on 408, 429, 500, 502, 503 and 504 responses, timeouts and connection
+    /// failures.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retryable_statuses: vec![
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_timeout: true,
+            retry_on_connect_error: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request exactly once
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Total number of attempts, including the first one
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry; doubled for every further attempt
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Upper bound for any single delay, including `Retry-After` values
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Randomize each backoff delay between half and all of its value
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Response statuses that should be retried
+    pub fn retryable_statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Whether requests that timed out should be retried
+    pub fn retry_on_timeout(mut self, retry: bool) -> Self {
+        self.retry_on_timeout = retry;
+        self
+    }
+
+    /// Whether requests that failed to connect should be retried
+    pub fn retry_on_connect_error(mut self, retry: bool) -> Self {
+        self.retry_on_connect_error = retry;
+        self
+    }
+
+    /// Whether a request may be sent again at all
+    pub(crate) fn allows_retry(&self, method: &Method, has_idempotency_key: bool) -> bool {
+        self.max_attempts > 1 && (is_idempotent(method) || has_idempotency_key)
+    }
+
+    pub(crate) fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    pub(crate) fn should_retry_error(&self, error: &reqwest::Error) -> bool {
+        (self.retry_on_timeout && error.is_timeout())
+            || (self.retry_on_connect_error && error.is_connect())
+    }
+
+    /// Delay before the given retry (1 for the first retry).
+    ///
+    /// A `Retry-After` value sent with a 429 or 503 takes precedence over
+    /// the computed backoff.
+    pub(crate) fn delay(
+        &self,
+        retry: u32,
+        status: Option<StatusCode>,
+        headers: Option<&HeaderMap>,
+    ) -> Duration {
+        let retry_after = match (status, headers) {
+            (
+                Some(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE),
+                Some(headers),
+            ) => parse_retry_after(headers),
+            _ => None,
+        };
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+
+        let exponent = retry.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for RetryPolicy {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response> {
+        let has_idempotency_key = request.headers().contains_key(IDEMPOTENCY_KEY_HEADER);
+        if !self.allows_retry(request.method(), has_idempotency_key) {
+            return next.run(request).await;
+        }
+
+        let mut attempt = 1;
+        loop {
+            // Each attempt resends a copy; the last one sends the original
+            let attempt_request = match request.try_clone() {
+                Some(copy) if attempt < self.max_attempts => copy,
+                _ => return next.with_attempt(attempt).run(request).await,
+            };
+
+            match next
+                .clone()
+                .with_attempt(attempt)
+                .run(attempt_request)
+                .await
+            {
+                Ok(response) if self.should_retry_status(response.status()) => {
+                    let status = Some(response.status());
+                    let delay = self.delay(attempt, status, Some(response.headers()));
+                    tokio::time::sleep(delay).await;
+                }
+                Err(BlindPayError::RequestFailed(err)) if self.should_retry_error(&err) => {
+                    tokio::time::sleep(self.delay(attempt, None, None)).await;
+                }
+                result => return result,
+            }
+            attempt += 1;
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// Parse a `Retry-After` header given in seconds or as an HTTP date
+///
+/// Values too large for a `Duration` saturate, and dates in the past give
+/// no delay.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<f64>() {
+        return (seconds.is_finite() && seconds >= 0.0)
+            .then(|| Duration::try_from_secs_f64(seconds).unwrap_or(Duration::MAX));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff_without_jitter() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(350))
+            .jitter(false);
+
+        assert_eq!(policy.delay(1, None, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, None, None), Duration::from_millis(200));
+        assert_eq!(policy.delay(3, None, None), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::default().base_delay(Duration::from_millis(100));
+        for _ in 0..100 {
+            let delay = policy.delay(1, None, None);
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_is_honoured() {
+        let policy = RetryPolicy::default().max_delay(Duration::from_secs(10));
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "3".parse().unwrap());
+
+        assert_eq!(
+            policy.delay(1, Some(StatusCode::TOO_MANY_REQUESTS), Some(&headers)),
+            Duration::from_secs(3)
+        );
+
+        headers.insert(RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(
+            policy.delay(1, Some(StatusCode::SERVICE_UNAVAILABLE), Some(&headers)),
+            Duration::from_secs(10)
+        );
+
+        headers.insert(RETRY_AFTER, "1e20".parse().unwrap());
+        assert_eq!(
+            policy.delay(1, Some(StatusCode::SERVICE_UNAVAILABLE), Some(&headers)),
+            Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_accepts_http_dates() {
+        let policy = RetryPolicy::default().max_delay(Duration::from_secs(600));
+        let mut headers = HeaderMap::new();
+        let later = SystemTime::now() + Duration::from_secs(120);
+        headers.insert(RETRY_AFTER, httpdate::fmt_http_date(later).parse().unwrap());
+
+        let delay = policy.delay(1, Some(StatusCode::TOO_MANY_REQUESTS), Some(&headers));
+        assert!(delay > Duration::from_secs(110) && delay <= Duration::from_secs(120));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(
+            policy.delay(1, Some(StatusCode::TOO_MANY_REQUESTS), Some(&headers)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_post_requires_idempotency_key() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows_retry(&Method::GET, false));
+        assert!(policy.allows_retry(&Method::DELETE, false));
+        assert!(!policy.allows_retry(&Method::POST, false));
+        assert!(policy.allows_retry(&Method::POST, true));
+        assert!(!RetryPolicy::none().allows_retry(&Method::GET, false));
+    }
+}
@@ -0,0 +1,198 @@
+//! Wallet hooks used by the payout orchestrators
+//!
+//! The SDK never holds private keys.
+//! [`PayoutsResource::execute`](crate::resources::payouts::PayoutsResource::execute)
+//! drives a [`PayoutSigner`], which knows how a payout is authorized on one
+//! chain and hands the actual signing to your own wallet code:
+//!
+//! - EVM: [`EvmPayoutSigner`] around an [`EvmSigner`] that sends the token approval
+//! - Stellar: `stellar::StellarPayoutSigner` around a [`TransactionSigner`]
+//!   (`stellar` feature)
+//! - Solana: `solana::SolanaPayoutSigner` around a [`TransactionSigner`]
+//!   (`solana` feature)
+
+use crate::error::{BlindPayError, Result};
+use crate::resources::payouts::{CreatePayoutResponse, PayoutsResource};
+use crate::resources::quotes::{ContractInfo, CreateQuoteResponse};
+use crate::types::Network;
+use async_trait::async_trait;
+
+#[cfg(feature = "solana")]
+pub mod solana;
+#[cfg(feature = "stellar")]
+pub mod stellar;
+
+/// Error returned by wallet code, kept as the source of
+/// [`BlindPayError::SigningFailed`](crate::BlindPayError::SigningFailed)
+pub type SignerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Signs and submits the token approval of an EVM payout
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::resources::quotes::ContractInfo;
+/// # use blindpay::signing::{EvmSigner, SignerError};
+/// struct MyWallet {
+///     address: String,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl EvmSigner for MyWallet {
+///     fn address(&self) -> String {
+///         self.address.clone()
+///     }
+///
+///     async fn approve(&self, contract: &ContractInfo) -> Result<String, SignerError> {
+///         // Call `contract.function_name` ("approve") on the token at
+///         // `contract.address` for `contract.blindpay_contract_address` and
+///         // `contract.amount`, wait for it to be mined and return its hash
+///         todo!()
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait EvmSigner: Send + Sync {
+    /// Address the stablecoins are sent from
+    fn address(&self) -> String;
+
+    /// Approve the BlindPay contract to spend the quoted amount
+    ///
+    /// `contract` carries the token address, ABI, function name, spender
+    /// (`blindpay_contract_address`), amount in token base units and chain.
+    /// Returns the hash of the approval transaction once it is mined.
+    async fn approve(&self, contract: &ContractInfo) -> std::result::Result<String, SignerError>;
+}
+
+/// Signs a transaction prepared by BlindPay for a Stellar or Solana payout
+#[async_trait]
+pub trait TransactionSigner: Send + Sync {
+    /// Address the stablecoins are sent from
+    fn address(&self) -> String;
+
+    /// Sign a base64 encoded transaction and return it base64 encoded
+    ///
+    /// Stellar payouts pass a transaction envelope XDR, Solana payouts a
+    /// serialized transaction.
+    async fn sign_transaction(&self, transaction: &str)
+        -> std::result::Result<String, SignerError>;
+}
+
+/// How a payout is authorized and submitted on one chain
+///
+/// [`PayoutsResource::execute`](crate::resources::payouts::PayoutsResource::execute)
+/// calls [`prepare`](Self::prepare), [`sign`](Self::sign) and
+/// [`submit`](Self::submit) in order, checking the quote has not expired in
+/// between.
+#[async_trait]
+pub trait PayoutSigner: Send + Sync {
+    /// What has to be signed before the payout can be submitted
+    type Transaction: Send;
+
+    /// Address the stablecoins are sent from
+    fn address(&self) -> String;
+
+    /// Whether payouts on `network` go through this signer
+    fn supports(&self, network: &Network) -> bool;
+
+    /// Build the transaction to sign for `quote`
+    async fn prepare(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+    ) -> Result<Self::Transaction>;
+
+    /// Sign (or send) the prepared transaction and return the value passed
+    /// to [`submit`](Self::submit)
+    async fn sign(&self, transaction: Self::Transaction) -> Result<String>;
+
+    /// Create the payout for `quote` once the transaction is signed
+    ///
+    /// Create it through `payouts`, which sends the idempotency key of the
+    /// [`execute`](PayoutsResource::execute) call.
+    async fn submit(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+        signed_transaction: String,
+    ) -> Result<CreatePayoutResponse>;
+}
+
+/// [`PayoutSigner`] for EVM networks
+///
+/// Signing sends the token approval described by the quote's contract
+/// through the wrapped [`EvmSigner`]; the payout is then created from its
+/// address.
+pub struct EvmPayoutSigner<S> {
+    wallet: S,
+}
+
+impl<S: EvmSigner> EvmPayoutSigner<S> {
+    pub fn new(wallet: S) -> Self {
+        Self { wallet }
+    }
+}
+
+#[async_trait]
+impl<S: EvmSigner> PayoutSigner for EvmPayoutSigner<S> {
+    type Transaction = ContractInfo;
+
+    fn address(&self) -> String {
+        self.wallet.address()
+    }
+
+    fn supports(&self, network: &Network) -> bool {
+        network.is_evm()
+    }
+
+    async fn prepare(
+        &self,
+        _payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+    ) -> Result<ContractInfo> {
+        quote
+            .contract
+            .clone()
+            .ok_or(BlindPayError::MissingField("contract"))
+    }
+
+    async fn sign(&self, contract: ContractInfo) -> Result<String> {
+        self.wallet
+            .approve(&contract)
+            .await
+            .map_err(BlindPayError::SigningFailed)
+    }
+
+    async fn submit(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+        _approval_transaction: String,
+    ) -> Result<CreatePayoutResponse> {
+        payouts.create_evm(&quote.id, &self.wallet.address()).await
+    }
+}
+
+#[async_trait]
+impl<S: EvmSigner + ?Sized> EvmSigner for &S {
+    fn address(&self) -> String {
+        (**self).address()
+    }
+
+    async fn approve(&self, contract: &ContractInfo) -> std::result::Result<String, SignerError> {
+        (**self).approve(contract).await
+    }
+}
+
+#[async_trait]
+impl<S: TransactionSigner + ?Sized> TransactionSigner for &S {
+    fn address(&self) -> String {
+        (**self).address()
+    }
+
+    async fn sign_transaction(
+        &self,
+        transaction: &str,
+    ) -> std::result::Result<String, SignerError> {
+        (**self).sign_transaction(transaction).await
+    }
+}
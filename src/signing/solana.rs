@@ -0,0 +1,88 @@
+//! Solana payouts, behind the `solana` feature
+
+use super::{PayoutSigner, TransactionSigner};
+use crate::error::{BlindPayError, Result};
+use crate::resources::payouts::{
+    CreatePayoutResponse, PayoutsResource, PrepareSolanaDelegationInput,
+};
+use crate::resources::quotes::CreateQuoteResponse;
+use crate::types::Network;
+use async_trait::async_trait;
+
+/// [`PayoutSigner`] for Solana
+///
+/// Asks BlindPay for a transaction delegating the quoted token amount from
+/// the wallet, has the wrapped [`TransactionSigner`] sign it and submits the
+/// payout with the signed transaction.
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::resources::payouts::ExecutePayoutOptions;
+/// # use blindpay::resources::quotes::CreateQuoteInput;
+/// # use blindpay::signing::{solana::SolanaPayoutSigner, TransactionSigner};
+/// # async fn example(wallet: impl TransactionSigner, input: CreateQuoteInput) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let signer = SolanaPayoutSigner::new(wallet);
+/// let executed = client.payouts().execute(input, &signer, ExecutePayoutOptions::new()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct SolanaPayoutSigner<W> {
+    wallet: W,
+}
+
+impl<W: TransactionSigner> SolanaPayoutSigner<W> {
+    pub fn new(wallet: W) -> Self {
+        Self { wallet }
+    }
+}
+
+#[async_trait]
+impl<W: TransactionSigner> PayoutSigner for SolanaPayoutSigner<W> {
+    /// Base64 serialized transaction
+    type Transaction = String;
+
+    fn address(&self) -> String {
+        self.wallet.address()
+    }
+
+    fn supports(&self, network: &Network) -> bool {
+        matches!(network, Network::Solana | Network::SolanaDevnet)
+    }
+
+    async fn prepare(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+    ) -> Result<String> {
+        let contract = quote
+            .contract
+            .as_ref()
+            .ok_or(BlindPayError::MissingField("contract"))?;
+        let input = PrepareSolanaDelegationInput {
+            owner_address: self.wallet.address(),
+            token_address: contract.address.clone(),
+            amount: contract.amount.clone(),
+        };
+        Ok(payouts.prepare_solana_delegation(input).await?.transaction)
+    }
+
+    async fn sign(&self, transaction: String) -> Result<String> {
+        self.wallet
+            .sign_transaction(&transaction)
+            .await
+            .map_err(BlindPayError::SigningFailed)
+    }
+
+    async fn submit(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+        signed_transaction: String,
+    ) -> Result<CreatePayoutResponse> {
+        payouts
+            .create_solana(&quote.id, &self.wallet.address(), Some(signed_transaction))
+            .await
+    }
+}
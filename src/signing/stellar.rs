@@ -0,0 +1,83 @@
+//! Stellar payouts, behind the `stellar` feature
+
+use super::{PayoutSigner, TransactionSigner};
+use crate::error::{BlindPayError, Result};
+use crate::resources::payouts::{CreatePayoutResponse, CreateStellarPayoutInput, PayoutsResource};
+use crate::resources::quotes::CreateQuoteResponse;
+use crate::types::Network;
+use async_trait::async_trait;
+
+/// [`PayoutSigner`] for Stellar
+///
+/// Asks BlindPay for the token authorization transaction of the quote, has
+/// the wrapped [`TransactionSigner`] sign its XDR and submits the payout
+/// with the signed envelope.
+///
+/// # Example
+/// ```no_run
+/// # use blindpay::BlindPay;
+/// # use blindpay::resources::payouts::ExecutePayoutOptions;
+/// # use blindpay::resources::quotes::CreateQuoteInput;
+/// # use blindpay::signing::{stellar::StellarPayoutSigner, TransactionSigner};
+/// # async fn example(wallet: impl TransactionSigner, input: CreateQuoteInput) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = BlindPay::new("api-key", "instance-id")?;
+/// let signer = StellarPayoutSigner::new(wallet);
+/// let executed = client.payouts().execute(input, &signer, ExecutePayoutOptions::new()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct StellarPayoutSigner<W> {
+    wallet: W,
+}
+
+impl<W: TransactionSigner> StellarPayoutSigner<W> {
+    pub fn new(wallet: W) -> Self {
+        Self { wallet }
+    }
+}
+
+#[async_trait]
+impl<W: TransactionSigner> PayoutSigner for StellarPayoutSigner<W> {
+    /// Base64 transaction envelope XDR
+    type Transaction = String;
+
+    fn address(&self) -> String {
+        self.wallet.address()
+    }
+
+    fn supports(&self, network: &Network) -> bool {
+        matches!(network, Network::Stellar | Network::StellarTestnet)
+    }
+
+    async fn prepare(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+    ) -> Result<String> {
+        let authorization = payouts
+            .authorize_stellar_token(&quote.id, &self.wallet.address())
+            .await?;
+        Ok(authorization.transaction_hash)
+    }
+
+    async fn sign(&self, xdr: String) -> Result<String> {
+        self.wallet
+            .sign_transaction(&xdr)
+            .await
+            .map_err(BlindPayError::SigningFailed)
+    }
+
+    async fn submit(
+        &self,
+        payouts: &PayoutsResource,
+        quote: &CreateQuoteResponse,
+        signed_transaction: String,
+    ) -> Result<CreatePayoutResponse> {
+        let input = CreateStellarPayoutInput {
+            quote_id: quote.id.clone(),
+            sender_wallet_address: self.wallet.address(),
+            signed_transaction: Some(signed_transaction),
+        };
+        payouts.create_stellar(input).await
+    }
+}
@@ -0,0 +1,286 @@
+//! A local, stateful fake of the BlindPay API for tests, behind the
+//! `test-util` feature
+//!
+//! [`FakeBlindPay`] listens on a random local port and keeps receivers, bank
+//! accounts, quotes, payouts and webhook endpoints in memory. Payouts only
+//! move when told to, and every change is delivered as a signed webhook to
+//! the endpoints registered through the regular webhook endpoint API.
+//!
+//! # Example
+//! ```no_run
+//! # use blindpay::testing::FakeBlindPay;
+//! # use blindpay::types::TransactionStatus;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let fake = FakeBlindPay::start().await?;
+//! let client = fake.client();
+//!
+//! // ... create a receiver, bank account, quote and payout through `client` ...
+//! # let payout_id = "pa_000001";
+//!
+//! fake.complete_payout(payout_id).await;
+//! let payout = client.payouts().get(payout_id).await?;
+//! assert_eq!(payout.status, TransactionStatus::Completed);
+//! # Ok(())
+//! # }
+//! ```
+
+mod state;
+
+use crate::client::BlindPay;
+use crate::polling::TrackingStage;
+use crate::resources::payouts::Payout;
+use crate::resources::receivers::KycStatus;
+use crate::resources::webhooks::{WebhookEvent, WebhookHeaders, WebhookVerifier};
+use crate::types::TrackingStatus;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use serde_json::Value;
+use state::State;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+/// API key accepted by [`FakeBlindPay`]
+pub const API_KEY: &str = "test-api-key";
+
+/// Instance id served by [`FakeBlindPay`]
+pub const INSTANCE_ID: &str = "in_test";
+
+/// A webhook sent by [`FakeBlindPay`]
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub endpoint_id: String,
+    pub url: String,
+    pub event: WebhookEvent,
+    /// Signature headers sent with the delivery
+    pub headers: WebhookHeaders,
+    /// The signed body, as sent
+    pub payload: String,
+    /// HTTP status returned by the endpoint, `None` when it could not be reached
+    pub status: Option<u16>,
+}
+
+/// A local fake of the BlindPay API
+///
+/// Stops serving when dropped.
+pub struct FakeBlindPay {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    http: reqwest::Client,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl FakeBlindPay {
+    /// Start serving on a random port of 127.0.0.1
+    pub async fn start() -> std::io::Result<Self> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .map_err(std::io::Error::other)?;
+
+        let service_state = state.clone();
+        let service_http = http.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = service_state.clone();
+            let http = service_http.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(state.clone(), http.clone(), request)
+                }))
+            }
+        });
+
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+        let server = Server::from_tcp(listener)
+            .map_err(std::io::Error::other)?
+            .serve(make_service)
+            .with_graceful_shutdown(async {
+                shutdown_rx.await.ok();
+            });
+        tokio::spawn(server);
+
+        Ok(Self {
+            addr,
+            state,
+            http,
+            _shutdown: shutdown,
+        })
+    }
+
+    /// Base URL to pass to [`BlindPayBuilder::base_url`](crate::BlindPayBuilder::base_url)
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// A client pointed at this fake with [`API_KEY`] and [`INSTANCE_ID`]
+    pub fn client(&self) -> BlindPay {
+        BlindPay::builder()
+            .api_key(API_KEY)
+            .instance_id(INSTANCE_ID)
+            .base_url(self.url())
+            .build()
+            .expect("the fake client configuration is valid")
+    }
+
+    /// Every webhook sent so far, oldest first
+    pub fn deliveries(&self) -> Vec<WebhookDelivery> {
+        self.state.lock().unwrap().deliveries.clone()
+    }
+
+    /// Move one tracking stage of a payout to `step`
+    ///
+    /// Completing [`TrackingStage::Complete`] completes the payout and sends
+    /// `payout.complete`; any other change sends `payout.update`.
+    ///
+    /// # Panics
+    /// When no payout has this id.
+    pub async fn advance_payout(
+        &self,
+        payout_id: &str,
+        stage: TrackingStage,
+        step: TrackingStatus,
+    ) -> Payout {
+        let (payout, event) = self
+            .state
+            .lock()
+            .unwrap()
+            .advance_payout(payout_id, stage, &step);
+        self.deliver(event, payout.clone()).await;
+        serde_json::from_value(payout).expect("the fake stores valid payouts")
+    }
+
+    /// Complete every tracking stage of a payout in order
+    ///
+    /// # Panics
+    /// When no payout has this id.
+    pub async fn complete_payout(&self, payout_id: &str) -> Payout {
+        for stage in [
+            TrackingStage::Transaction,
+            TrackingStage::Payment,
+            TrackingStage::Liquidity,
+        ] {
+            self.advance_payout(payout_id, stage, TrackingStatus::Completed)
+                .await;
+        }
+        self.advance_payout(
+            payout_id,
+            TrackingStage::Complete,
+            TrackingStatus::Completed,
+        )
+        .await
+    }
+
+    /// Mark a payout as failed and send `payout.update`
+    ///
+    /// # Panics
+    /// When no payout has this id.
+    pub async fn fail_payout(&self, payout_id: &str) -> Payout {
+        let payout = self.state.lock().unwrap().fail_payout(payout_id);
+        self.deliver(WebhookEvent::PayoutUpdate, payout.clone())
+            .await;
+        serde_json::from_value(payout).expect("the fake stores valid payouts")
+    }
+
+    /// Change the KYC status of a receiver and send `receiver.update`
+    ///
+    /// # Panics
+    /// When no receiver has this id.
+    pub async fn set_kyc_status(&self, receiver_id: &str, kyc_status: KycStatus) {
+        let receiver = self
+            .state
+            .lock()
+            .unwrap()
+            .set_kyc_status(receiver_id, &kyc_status);
+        self.deliver(WebhookEvent::ReceiverUpdate, receiver).await;
+    }
+
+    async fn deliver(&self, event: WebhookEvent, object: Value) {
+        deliver(&self.state, &self.http, event, object).await;
+    }
+}
+
+async fn handle(
+    state: Arc<Mutex<State>>,
+    http: reqwest::Client,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+
+    let authorized = parts
+        .headers
+        .get(hyper::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        == Some(format!("Bearer {}", API_KEY).as_str());
+
+    let outcome = if authorized {
+        state.lock().unwrap().route(
+            parts.method.as_str(),
+            parts.uri.path(),
+            parts.uri.query().unwrap_or(""),
+            &body,
+        )
+    } else {
+        state::Outcome::error(401, "unauthorized", "Invalid API key")
+    };
+
+    if let Some((event, object)) = outcome.event {
+        deliver(&state, &http, event, object).await;
+    }
+
+    let response = Response::builder()
+        .status(outcome.status)
+        .header(hyper::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(outcome.body.to_string()))
+        .expect("valid response parts");
+    Ok(response)
+}
+
+/// Sign `object` for every endpoint subscribed to `event` and post it
+async fn deliver(
+    state: &Mutex<State>,
+    http: &reqwest::Client,
+    event: WebhookEvent,
+    mut object: Value,
+) {
+    object["webhook_event"] = Value::String(event.to_string());
+    let payload = object.to_string();
+    let endpoints = state.lock().unwrap().subscribed_endpoints(&event);
+
+    for endpoint in endpoints {
+        let msg_id = state.lock().unwrap().next_id("msg");
+        let timestamp = state::unix_now();
+        let signature = WebhookVerifier::new(&endpoint.secret)
+            .expect("the fake generates valid secrets")
+            .sign(&msg_id, timestamp, payload.as_bytes());
+        let headers = WebhookHeaders::new(msg_id, timestamp.to_string(), signature);
+
+        let status = http
+            .post(&endpoint.url)
+            .header("content-type", "application/json")
+            .header("svix-id", &headers.id)
+            .header("svix-timestamp", &headers.timestamp)
+            .header("svix-signature", &headers.signature)
+            .body(payload.clone())
+            .send()
+            .await
+            .ok()
+            .map(|response| response.status().as_u16());
+
+        state.lock().unwrap().deliveries.push(WebhookDelivery {
+            endpoint_id: endpoint.id,
+            url: endpoint.url,
+            event: event.clone(),
+            headers,
+            payload: payload.clone(),
+            status,
+        });
+    }
+}
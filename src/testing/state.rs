@@ -0,0 +1,695 @@
+//! In-memory store and request routing of [`FakeBlindPay`](super::FakeBlindPay)
+
+use super::{WebhookDelivery, INSTANCE_ID};
+use crate::money::Amount;
+use crate::polling::TrackingStage;
+use crate::resources::receivers::KycStatus;
+use crate::resources::webhooks::WebhookEvent;
+use crate::types::{Network, TrackingStatus};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long a fake quote stays valid
+const QUOTE_VALIDITY: Duration = Duration::from_secs(5 * 60);
+
+/// Page size of list endpoints when no `limit` is given
+const DEFAULT_LIMIT: usize = 10;
+
+/// Response of one request, plus the webhook it triggers if any
+pub(super) struct Outcome {
+    pub status: u16,
+    pub body: Value,
+    pub event: Option<(WebhookEvent, Value)>,
+}
+
+impl Outcome {
+    fn ok(data: Value) -> Self {
+        Self {
+            status: 200,
+            body: json!({ "data": data, "error": null }),
+            event: None,
+        }
+    }
+
+    pub(super) fn error(status: u16, code: &str, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "data": null, "error": { "message": message, "code": code } }),
+            event: None,
+        }
+    }
+
+    fn not_found(what: &str) -> Self {
+        Self::error(404, "not_found", &format!("{} not found", what))
+    }
+
+    fn with_event(mut self, event: WebhookEvent, object: Value) -> Self {
+        self.event = Some((event, object));
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Endpoint {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub secret: String,
+    pub created_at: String,
+}
+
+#[derive(Default)]
+pub(super) struct State {
+    counter: u64,
+    receivers: Vec<Value>,
+    bank_accounts: Vec<Value>,
+    quotes: Vec<Value>,
+    payouts: Vec<Value>,
+    endpoints: Vec<Endpoint>,
+    pub deliveries: Vec<WebhookDelivery>,
+}
+
+impl State {
+    pub fn next_id(&mut self, prefix: &str) -> String {
+        self.counter += 1;
+        format!("{}_{:06}", prefix, self.counter)
+    }
+
+    pub fn subscribed_endpoints(&self, event: &WebhookEvent) -> Vec<Endpoint> {
+        self.endpoints
+            .iter()
+            .filter(|endpoint| endpoint.events.contains(event))
+            .cloned()
+            .collect()
+    }
+
+    pub fn route(&mut self, method: &str, path: &str, query: &str, body: &[u8]) -> Outcome {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let query: HashMap<String, String> = serde_urlencoded::from_str(query).unwrap_or_default();
+        let body = if body.is_empty() {
+            Value::Null
+        } else {
+            match serde_json::from_slice(body) {
+                Ok(body) => body,
+                Err(_) => return Outcome::error(400, "invalid_body", "Body is not valid JSON"),
+            }
+        };
+
+        let rest = match segments.as_slice() {
+            ["e", "payouts", id] if method == "GET" => return self.get_payout(id),
+            ["instances", instance_id, rest @ ..] if *instance_id == INSTANCE_ID => rest,
+            ["instances", ..] => return Outcome::not_found("Instance"),
+            _ => return route_not_found(method, path),
+        };
+
+        match (method, rest) {
+            ("POST", ["receivers"]) => self.create_receiver(body),
+            ("GET", ["receivers"]) => self.list_receivers(&query),
+            ("GET", ["receivers", id]) => find(&self.receivers, id)
+                .map(|receiver| Outcome::ok(receiver.clone()))
+                .unwrap_or_else(|| Outcome::not_found("Receiver")),
+            ("DELETE", ["receivers", id]) => remove(&mut self.receivers, id, "Receiver"),
+            ("POST", ["receivers", receiver_id, "bank-accounts"]) => {
+                self.create_bank_account(receiver_id, body)
+            }
+            ("GET", ["receivers", receiver_id, "bank-accounts"]) => {
+                let data: Vec<Value> = self
+                    .bank_accounts
+                    .iter()
+                    .filter(|account| account["receiver_id"] == *receiver_id)
+                    .cloned()
+                    .collect();
+                Outcome::ok(json!({ "data": data }))
+            }
+            ("GET", ["receivers", _, "bank-accounts", id]) => find(&self.bank_accounts, id)
+                .map(|account| Outcome::ok(account.clone()))
+                .unwrap_or_else(|| Outcome::not_found("Bank account")),
+            ("DELETE", ["receivers", _, "bank-accounts", id]) => {
+                remove(&mut self.bank_accounts, id, "Bank account")
+            }
+            ("POST", ["quotes"]) => self.create_quote(body),
+            ("POST", ["payouts", "stellar", "authorize"]) => {
+                let quote_id = str_field(&body, "quote_id");
+                match find(&self.quotes, quote_id) {
+                    Some(_) => Outcome::ok(json!({
+                        "transaction_hash": format!("stellar-authorization-{}", quote_id),
+                    })),
+                    None => Outcome::not_found("Quote"),
+                }
+            }
+            ("POST", ["payouts", family @ ("evm" | "stellar" | "solana")]) => {
+                self.create_payout(family, body)
+            }
+            ("GET", ["payouts"]) => {
+                let payouts: Vec<Value> = self
+                    .payouts
+                    .iter()
+                    .filter(|payout| {
+                        matches_filters(
+                            payout,
+                            &query,
+                            &["receiver_id", "status", "network", "currency"],
+                        )
+                    })
+                    .cloned()
+                    .collect();
+                Outcome::ok(page(payouts, &query))
+            }
+            ("GET", ["payouts", id]) => self.get_payout(id),
+            ("POST", ["prepare-delegate-solana"]) => Outcome::ok(json!({
+                "success": true,
+                "transaction": format!("solana-delegation-{}", str_field(&body, "amount")),
+            })),
+            ("POST", ["webhook-endpoints"]) => self.create_endpoint(body),
+            ("GET", ["webhook-endpoints"]) => {
+                let endpoints: Vec<Value> = self
+                    .endpoints
+                    .iter()
+                    .map(|endpoint| {
+                        json!({
+                            "id": endpoint.id,
+                            "url": endpoint.url,
+                            "events": endpoint.events,
+                            "last_event_at": endpoint.created_at,
+                            "instance_id": INSTANCE_ID,
+                            "created_at": endpoint.created_at,
+                            "updated_at": endpoint.created_at,
+                        })
+                    })
+                    .collect();
+                Outcome::ok(Value::Array(endpoints))
+            }
+            ("DELETE", ["webhook-endpoints", id]) => {
+                let before = self.endpoints.len();
+                self.endpoints.retain(|endpoint| endpoint.id != *id);
+                if self.endpoints.len() == before {
+                    Outcome::not_found("Webhook endpoint")
+                } else {
+                    Outcome::ok(Value::Null)
+                }
+            }
+            ("GET", ["webhook-endpoints", id, "secret"]) => {
+                match self.endpoints.iter().find(|endpoint| endpoint.id == *id) {
+                    Some(endpoint) => Outcome::ok(json!({ "key": endpoint.secret })),
+                    None => Outcome::not_found("Webhook endpoint"),
+                }
+            }
+            _ => route_not_found(method, path),
+        }
+    }
+
+    fn create_receiver(&mut self, body: Value) -> Outcome {
+        let Value::Object(input) = body else {
+            return Outcome::error(400, "invalid_body", "Expected a JSON object");
+        };
+        for field in ["type", "kyc_type", "email"] {
+            if !input.contains_key(field) {
+                return missing_field(field);
+            }
+        }
+
+        let now = now_rfc3339();
+        let mut receiver = json!({
+            "is_tos_accepted": true,
+            "tax_id": "",
+            "address_line_1": "",
+            "city": "",
+            "state_province_region": "",
+            "country": "US",
+            "postal_code": "",
+            "proof_of_address_doc_type": "UTILITY_BILL",
+            "proof_of_address_doc_file": "",
+            "aiprise_validation_key": "",
+            "limit": { "per_transaction": 100_000, "daily": 200_000, "monthly": 1_000_000 },
+        });
+        merge(&mut receiver, input);
+        merge(
+            &mut receiver,
+            json_object(json!({
+                "id": self.next_id("re"),
+                "kyc_status": KycStatus::Verifying,
+                "instance_id": INSTANCE_ID,
+                "created_at": now,
+                "updated_at": now,
+            })),
+        );
+
+        self.receivers.push(receiver.clone());
+        Outcome::ok(json!({ "id": receiver["id"] })).with_event(WebhookEvent::ReceiverNew, receiver)
+    }
+
+    fn list_receivers(&self, query: &HashMap<String, String>) -> Outcome {
+        let receivers: Vec<Value> = self
+            .receivers
+            .iter()
+            .filter(|receiver| {
+                matches_filters(
+                    receiver,
+                    query,
+                    &["external_id", "email", "kyc_status", "type"],
+                )
+            })
+            .filter(|receiver| {
+                let created_at = receiver["created_at"].as_str().unwrap_or_default();
+                query
+                    .get("created_after")
+                    .map_or(true, |after| created_at > after.as_str())
+                    && query
+                        .get("created_before")
+                        .map_or(true, |before| created_at < before.as_str())
+            })
+            .cloned()
+            .collect();
+        Outcome::ok(page(receivers, query))
+    }
+
+    fn create_bank_account(&mut self, receiver_id: &str, body: Value) -> Outcome {
+        if find(&self.receivers, receiver_id).is_none() {
+            return Outcome::not_found("Receiver");
+        }
+        let Value::Object(input) = body else {
+            return Outcome::error(400, "invalid_body", "Expected a JSON object");
+        };
+        for field in ["type", "name"] {
+            if !input.contains_key(field) {
+                return missing_field(field);
+            }
+        }
+
+        let mut account = Value::Object(input);
+        if let Some(account_type) = account.get("account_type").cloned() {
+            account["account_type_detail"] = account_type;
+        }
+        merge(
+            &mut account,
+            json_object(json!({
+                "id": self.next_id("ba"),
+                "receiver_id": receiver_id,
+                "created_at": now_rfc3339(),
+            })),
+        );
+
+        self.bank_accounts.push(account.clone());
+        Outcome::ok(account.clone()).with_event(WebhookEvent::BankAccountNew, account)
+    }
+
+    fn create_quote(&mut self, body: Value) -> Outcome {
+        for field in [
+            "bank_account_id",
+            "currency_type",
+            "request_amount",
+            "network",
+        ] {
+            if body.get(field).map_or(true, Value::is_null) {
+                return missing_field(field);
+            }
+        }
+        let Some(account) = find(&self.bank_accounts, str_field(&body, "bank_account_id")).cloned()
+        else {
+            return Outcome::not_found("Bank account");
+        };
+
+        let (currency, rate) = match account["type"].as_str().unwrap_or_default() {
+            "pix" => ("BRL", 5),
+            "spei_bitso" => ("MXN", 17),
+            "transfers_bitso" => ("ARS", 1000),
+            "ach_cop_bitso" => ("COP", 4000),
+            _ => ("USD", 1),
+        };
+        // Amounts are whole cents
+        let Some(requested) = serde_json::from_value::<Amount>(body["request_amount"].clone())
+            .ok()
+            .map(|amount| amount.round_dp(0))
+            .filter(|amount| !amount.is_negative())
+        else {
+            return invalid_field("request_amount");
+        };
+        let amounts = if body["currency_type"] == "receiver" {
+            let rate = i128::from(rate);
+            let sender = (requested.mantissa() + rate / 2) / rate;
+            Some((Amount::new(sender, 0), requested))
+        } else {
+            requested
+                .checked_mul(Amount::from(rate))
+                .map(|receiver| (requested, receiver))
+        };
+        let Some((sender_amount, receiver_amount)) = amounts else {
+            return invalid_field("request_amount");
+        };
+        // Token base units (6 decimals) from cents
+        let Some(token_amount) = sender_amount.checked_mul(Amount::from(10_000)) else {
+            return invalid_field("request_amount");
+        };
+
+        let network = str_field(&body, "network").to_string();
+        let contract = chain_id(&network).map(|chain_id| {
+            json!({
+                "abi": [],
+                "address": "0x0000000000000000000000000000000000000001",
+                "functionName": "approve",
+                "blindpayContractAddress": "0x0000000000000000000000000000000000000002",
+                "amount": token_amount.to_string(),
+                "network": { "name": network, "chainId": chain_id },
+            })
+        });
+        let expires_at = (SystemTime::now() + QUOTE_VALIDITY)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let quote = json!({
+            "id": self.next_id("qu"),
+            "expires_at": expires_at,
+            "commercial_quotation": rate,
+            "blindpay_quotation": rate,
+            "receiver_amount": receiver_amount,
+            "sender_amount": sender_amount,
+            "partner_fee_amount": 0,
+            "flat_fee": 0,
+            "contract": contract,
+            "receiver_local_amount": receiver_amount,
+            "description": body["description"],
+            // Kept for the payout created from this quote
+            "receiver_id": account["receiver_id"],
+            "network": network,
+            "token": body["token"].as_str().unwrap_or("USDC"),
+            "currency": currency,
+        });
+        self.quotes.push(quote.clone());
+        Outcome::ok(quote)
+    }
+
+    fn create_payout(&mut self, family: &str, body: Value) -> Outcome {
+        let quote_id = str_field(&body, "quote_id");
+        let Some(quote) = find(&self.quotes, quote_id).cloned() else {
+            return Outcome::not_found("Quote");
+        };
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        if quote["expires_at"].as_u64().unwrap_or_default() <= now_ms {
+            return Outcome::error(400, "quote_expired", "Quote has expired");
+        }
+        if self
+            .payouts
+            .iter()
+            .any(|payout| payout["quote_id"] == quote_id)
+        {
+            return Outcome::error(409, "quote_already_used", "Quote already has a payout");
+        }
+        let network = quote["network"].as_str().unwrap_or_default();
+        if network_family(network) != family {
+            return Outcome::error(
+                400,
+                "invalid_network",
+                &format!("Quote is for the {} network", network),
+            );
+        }
+        let Some(sender_wallet_address) = body["sender_wallet_address"].as_str() else {
+            return missing_field("sender_wallet_address");
+        };
+
+        let receiver = find(
+            &self.receivers,
+            quote["receiver_id"].as_str().unwrap_or_default(),
+        )
+        .cloned()
+        .unwrap_or_default();
+        let now = now_rfc3339();
+        let payout = json!({
+            "id": self.next_id("pa"),
+            "receiver_id": quote["receiver_id"],
+            "status": "processing",
+            "sender_wallet_address": sender_wallet_address,
+            "signed_transaction": body["signed_transaction"].as_str().unwrap_or_default(),
+            "quote_id": quote_id,
+            "instance_id": INSTANCE_ID,
+            "tracking_transaction": { "step": "processing", "status": "pending" },
+            "tracking_payment": { "step": "on_hold" },
+            "tracking_liquidity": { "step": "on_hold" },
+            "tracking_complete": { "step": "on_hold" },
+            "tracking_partner_fee": { "step": "on_hold" },
+            "created_at": now,
+            "updated_at": now,
+            "first_name": receiver["first_name"],
+            "last_name": receiver["last_name"],
+            "legal_name": receiver["legal_name"],
+            "network": network,
+            "token": quote["token"],
+            "description": quote["description"].as_str().unwrap_or_default(),
+            "sender_amount": quote["sender_amount"],
+            "receiver_amount": quote["receiver_amount"],
+            "partner_fee_amount": 0,
+            "commercial_quotation": quote["commercial_quotation"],
+            "blindpay_quotation": quote["blindpay_quotation"],
+            "total_fee_amount": 0,
+            "receiver_local_amount": quote["receiver_local_amount"],
+            "currency": quote["currency"],
+        });
+
+        self.payouts.push(payout.clone());
+        Outcome::ok(payout.clone()).with_event(WebhookEvent::PayoutNew, payout)
+    }
+
+    fn get_payout(&self, id: &str) -> Outcome {
+        find(&self.payouts, id)
+            .map(|payout| Outcome::ok(payout.clone()))
+            .unwrap_or_else(|| Outcome::not_found("Payout"))
+    }
+
+    fn create_endpoint(&mut self, body: Value) -> Outcome {
+        let Some(url) = body["url"].as_str() else {
+            return missing_field("url");
+        };
+        let events = serde_json::from_value(body["events"].clone()).unwrap_or_default();
+        let secret = format!("whsec_{}", BASE64.encode(uuid::Uuid::new_v4().as_bytes()));
+
+        let endpoint = Endpoint {
+            id: self.next_id("we"),
+            url: url.to_string(),
+            events,
+            secret,
+            created_at: now_rfc3339(),
+        };
+        let id = endpoint.id.clone();
+        self.endpoints.push(endpoint);
+        Outcome::ok(json!({ "id": id }))
+    }
+
+    pub fn advance_payout(
+        &mut self,
+        payout_id: &str,
+        stage: TrackingStage,
+        step: &TrackingStatus,
+    ) -> (Value, WebhookEvent) {
+        let payout = find_mut(&mut self.payouts, payout_id)
+            .unwrap_or_else(|| panic!("no payout with id {}", payout_id));
+        let now = now_rfc3339();
+        let completed = *step == TrackingStatus::Completed;
+
+        let section = &mut payout[stage_field(stage)];
+        section["step"] = json!(step);
+        if completed {
+            section["completed_at"] = json!(now);
+        }
+        match stage {
+            TrackingStage::Transaction if completed => {
+                section["status"] = json!("mined");
+                section["transaction_hash"] = json!(format!("0x{}", payout_id));
+            }
+            TrackingStage::Complete if completed => {
+                section["status"] = json!("completed");
+                section["transaction_hash"] = json!(format!("0x{}", payout_id));
+            }
+            _ => {}
+        }
+        payout["updated_at"] = json!(now);
+
+        let event = if stage == TrackingStage::Complete && completed {
+            payout["status"] = json!("completed");
+            WebhookEvent::PayoutComplete
+        } else {
+            WebhookEvent::PayoutUpdate
+        };
+        (payout.clone(), event)
+    }
+
+    pub fn fail_payout(&mut self, payout_id: &str) -> Value {
+        let payout = find_mut(&mut self.payouts, payout_id)
+            .unwrap_or_else(|| panic!("no payout with id {}", payout_id));
+        payout["status"] = json!("failed");
+        payout["updated_at"] = json!(now_rfc3339());
+        payout.clone()
+    }
+
+    pub fn set_kyc_status(&mut self, receiver_id: &str, kyc_status: &KycStatus) -> Value {
+        let receiver = find_mut(&mut self.receivers, receiver_id)
+            .unwrap_or_else(|| panic!("no receiver with id {}", receiver_id));
+        receiver["kyc_status"] = json!(kyc_status);
+        receiver["updated_at"] = json!(now_rfc3339());
+        receiver.clone()
+    }
+}
+
+fn route_not_found(method: &str, path: &str) -> Outcome {
+    Outcome::error(
+        404,
+        "not_found",
+        &format!("No route for {} {}", method, path),
+    )
+}
+
+fn missing_field(field: &str) -> Outcome {
+    Outcome::error(400, "validation_error", &format!("{} is required", field))
+}
+
+fn invalid_field(field: &str) -> Outcome {
+    Outcome::error(400, "validation_error", &format!("{} is invalid", field))
+}
+
+fn find<'a>(items: &'a [Value], id: &str) -> Option<&'a Value> {
+    items.iter().find(|item| item["id"] == id)
+}
+
+fn find_mut<'a>(items: &'a mut [Value], id: &str) -> Option<&'a mut Value> {
+    items.iter_mut().find(|item| item["id"] == id)
+}
+
+fn remove(items: &mut Vec<Value>, id: &str, what: &str) -> Outcome {
+    match items.iter().position(|item| item["id"] == id) {
+        Some(index) => {
+            items.remove(index);
+            Outcome::ok(Value::Null)
+        }
+        None => Outcome::not_found(what),
+    }
+}
+
+fn str_field<'a>(value: &'a Value, field: &str) -> &'a str {
+    value[field].as_str().unwrap_or_default()
+}
+
+fn json_object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+fn merge(target: &mut Value, fields: Map<String, Value>) {
+    if let Value::Object(target) = target {
+        target.extend(fields);
+    }
+}
+
+/// Whether `item` has every filter of `query` among `fields`
+fn matches_filters(item: &Value, query: &HashMap<String, String>, fields: &[&str]) -> bool {
+    fields.iter().all(|field| {
+        query.get(*field).map_or(true, |expected| {
+            item[*field].as_str() == Some(expected.as_str())
+        })
+    })
+}
+
+/// Apply `limit` and `starting_after` to `items` and wrap them with pagination metadata
+fn page(items: Vec<Value>, query: &HashMap<String, String>) -> Value {
+    let limit = query
+        .get("limit")
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(DEFAULT_LIMIT);
+    let start = query
+        .get("starting_after")
+        .and_then(|cursor| items.iter().position(|item| item["id"] == cursor.as_str()))
+        .map_or(0, |index| index + 1);
+
+    let data: Vec<Value> = items.iter().skip(start).take(limit).cloned().collect();
+    let has_more = start + data.len() < items.len();
+    let next_page = if has_more {
+        data.last().map(|item| item["id"].clone())
+    } else {
+        None
+    };
+    json!({
+        "data": data,
+        "pagination": { "has_more": has_more, "next_page": next_page, "prev_page": null },
+    })
+}
+
+fn stage_field(stage: TrackingStage) -> &'static str {
+    match stage {
+        TrackingStage::Transaction => "tracking_transaction",
+        TrackingStage::Payment => "tracking_payment",
+        TrackingStage::Liquidity => "tracking_liquidity",
+        TrackingStage::Complete => "tracking_complete",
+        TrackingStage::PartnerFee => "tracking_partner_fee",
+    }
+}
+
+/// Payout endpoint (`evm`, `stellar` or `solana`) serving a network
+fn network_family(network: &str) -> &'static str {
+    match Network::from(network) {
+        Network::Stellar | Network::StellarTestnet => "stellar",
+        Network::Solana | Network::SolanaDevnet => "solana",
+        other if other.is_evm() => "evm",
+        _ => "",
+    }
+}
+
+/// Chain id put in the quote contract of networks that approve a token spend
+fn chain_id(network: &str) -> Option<u64> {
+    match Network::from(network) {
+        Network::Ethereum => Some(1),
+        Network::Polygon => Some(137),
+        Network::Base => Some(8453),
+        Network::Arbitrum => Some(42161),
+        Network::PolygonAmoy => Some(80002),
+        Network::BaseSepolia => Some(84532),
+        Network::ArbitrumSepolia => Some(421614),
+        Network::Sepolia => Some(11155111),
+        Network::Solana => Some(101),
+        Network::SolanaDevnet => Some(103),
+        _ => None,
+    }
+}
+
+pub(super) fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// The current time as `YYYY-MM-DDTHH:MM:SS.mmmZ`
+fn now_rfc3339() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = now.as_secs() as i64;
+    let (days, time) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60,
+        now.subsec_millis()
+    )
+}
@@ -1,3 +1,5 @@
+pub use crate::country::Country;
+use crate::macros::api_enum;
 use serde::{Deserialize, Serialize};
 
 // Response wrapper types
@@ -23,168 +25,254 @@ pub struct BlindPayErrorResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
     pub message: String,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub errors: Option<Vec<FieldError>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// A point in time sent by the API, kept as the RFC 3339 text it arrived as
+///
+/// The same type with or without the `chrono` feature; the feature adds
+/// [`as_datetime`](Self::as_datetime) and a conversion from
+/// `chrono::DateTime<Utc>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The timestamp as sent by the API
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The timestamp as a UTC date and time, or `None` if it is not valid
+    /// RFC 3339
+    #[cfg(feature = "chrono")]
+    pub fn as_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::parse_from_rfc3339(&self.0)
+            .ok()
+            .map(|datetime| datetime.with_timezone(&chrono::Utc))
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self(value.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+impl AsRef<str> for Timestamp {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
 // Enums
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum CurrencyType {
-    Sender,
-    Receiver,
+api_enum! {
+    pub enum CurrencyType {
+        Sender => "sender",
+        Receiver => "receiver",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum Network {
-    Base,
-    Sepolia,
-    ArbitrumSepolia,
-    BaseSepolia,
-    Arbitrum,
-    Polygon,
-    PolygonAmoy,
-    Ethereum,
-    Stellar,
-    StellarTestnet,
-    Tron,
-    Solana,
-    SolanaDevnet,
+api_enum! {
+    pub enum Network {
+        Base => "base",
+        Sepolia => "sepolia",
+        ArbitrumSepolia => "arbitrum_sepolia",
+        BaseSepolia => "base_sepolia",
+        Arbitrum => "arbitrum",
+        Polygon => "polygon",
+        PolygonAmoy => "polygon_amoy",
+        Ethereum => "ethereum",
+        Stellar => "stellar",
+        StellarTestnet => "stellar_testnet",
+        Tron => "tron",
+        Solana => "solana",
+        SolanaDevnet => "solana_devnet",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum StablecoinToken {
-    USDC,
-    USDT,
-    USDB,
+api_enum! {
+    pub enum StablecoinToken {
+        USDC => "USDC",
+        USDT => "USDT",
+        USDB => "USDB",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum TransactionDocumentType {
-    Invoice,
-    PurchaseOrder,
-    DeliverySlip,
-    Contract,
-    CustomsDeclaration,
-    BillOfLading,
-    Others,
+api_enum! {
+    pub enum TransactionDocumentType {
+        Invoice => "invoice",
+        PurchaseOrder => "purchase_order",
+        DeliverySlip => "delivery_slip",
+        Contract => "contract",
+        CustomsDeclaration => "customs_declaration",
+        BillOfLading => "bill_of_lading",
+        Others => "others",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum BankAccountType {
-    Checking,
-    Saving,
+api_enum! {
+    pub enum BankAccountType {
+        Checking => "checking",
+        Saving => "saving",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum Currency {
-    USDC,
-    USDT,
-    USDB,
-    BRL,
-    USD,
-    MXN,
-    COP,
-    ARS,
+api_enum! {
+    pub enum Currency {
+        USDC => "USDC",
+        USDT => "USDT",
+        USDB => "USDB",
+        BRL => "BRL",
+        USD => "USD",
+        MXN => "MXN",
+        COP => "COP",
+        ARS => "ARS",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum Rail {
-    Wire,
-    Ach,
-    Pix,
-    SpeiBitso,
-    TransfersBitso,
-    AchCopBitso,
-    InternationalSwift,
-    Rtp,
+api_enum! {
+    pub enum Rail {
+        Wire => "wire",
+        Ach => "ach",
+        Pix => "pix",
+        SpeiBitso => "spei_bitso",
+        TransfersBitso => "transfers_bitso",
+        AchCopBitso => "ach_cop_bitso",
+        InternationalSwift => "international_swift",
+        Rtp => "rtp",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum AccountClass {
-    Individual,
-    Business,
+api_enum! {
+    pub enum AccountClass {
+        Individual => "individual",
+        Business => "business",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum TransactionStatus {
-    Refunded,
-    Processing,
-    Completed,
-    Failed,
-    OnHold,
+api_enum! {
+    pub enum TransactionStatus {
+        Refunded => "refunded",
+        Processing => "processing",
+        Completed => "completed",
+        Failed => "failed",
+        OnHold => "on_hold",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Country {
-    US,
-    BR,
-    MX,
-    AR,
-    CO,
-    // Add other countries as needed
+impl Network {
+    /// Whether payouts on this network go through an EVM token approval
+    pub fn is_evm(&self) -> bool {
+        matches!(
+            self,
+            Self::Base
+                | Self::Sepolia
+                | Self::ArbitrumSepolia
+                | Self::BaseSepolia
+                | Self::Arbitrum
+                | Self::Polygon
+                | Self::PolygonAmoy
+                | Self::Ethereum
+        )
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl TransactionStatus {
+    /// Whether the transaction can no longer change (completed, failed or refunded)
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Refunded)
+    }
+}
+
+/// Pagination parameters sent as query string on list requests
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PaginationParams {
-    pub limit: Option<String>,
-    pub offset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ending_before: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginationMetadata {
     pub has_more: bool,
-    pub next_page: String,
-    pub prev_page: String,
+    pub next_page: Option<String>,
+    pub prev_page: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ArgentinaTransfers {
-    CVU,
-    CBU,
-    ALIAS,
+api_enum! {
+    pub enum ArgentinaTransfers {
+        CVU => "CVU",
+        CBU => "CBU",
+        ALIAS => "ALIAS",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum AchCopDocument {
-    CC,
-    CE,
-    NIT,
-    PASS,
-    PEP,
+api_enum! {
+    pub enum AchCopDocument {
+        CC => "CC",
+        CE => "CE",
+        NIT => "NIT",
+        PASS => "PASS",
+        PEP => "PEP",
+    }
 }
 
 // Tracking types
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum TrackingStatus {
-    Processing,
-    OnHold,
-    Completed,
+api_enum! {
+    pub enum TrackingStatus {
+        Processing => "processing",
+        OnHold => "on_hold",
+        Completed => "completed",
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum EstimatedTimeOfArrival {
-    #[serde(rename = "5_min")]
-    FiveMin,
-    #[serde(rename = "30_min")]
-    ThirtyMin,
-    #[serde(rename = "2_hours")]
-    TwoHours,
-    #[serde(rename = "1_business_day")]
-    OneBusinessDay,
-    #[serde(rename = "2_business_days")]
-    TwoBusinessDays,
-    #[serde(rename = "5_business_days")]
-    FiveBusinessDays,
+api_enum! {
+    pub enum EstimatedTimeOfArrival {
+        FiveMin => "5_min",
+        ThirtyMin => "30_min",
+        TwoHours => "2_hours",
+        OneBusinessDay => "1_business_day",
+        TwoBusinessDays => "2_business_days",
+        FiveBusinessDays => "5_business_days",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -192,7 +280,7 @@ pub struct PayinTrackingTransaction {
     pub step: TrackingStatus,
     pub status: Option<String>,
     pub external_id: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
     pub sender_name: Option<String>,
     pub sender_tax_id: Option<String>,
     pub sender_bank_code: Option<String>,
@@ -206,21 +294,21 @@ pub struct PayinTrackingTransaction {
 pub struct PayinTrackingPayment {
     pub step: TrackingStatus,
     pub provider_name: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PayinTrackingComplete {
     pub step: TrackingStatus,
     pub transaction_hash: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PayinTrackingPartnerFee {
     pub step: TrackingStatus,
     pub transaction_hash: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -228,7 +316,7 @@ pub struct PayoutTrackingTransaction {
     pub step: TrackingStatus,
     pub status: String,
     pub transaction_hash: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -244,7 +332,7 @@ pub struct PayoutTrackingPayment {
     pub recipient_account_number: Option<String>,
     pub recipient_account_type: Option<String>,
     pub estimated_time_of_arrival: Option<EstimatedTimeOfArrival>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -253,7 +341,7 @@ pub struct PayoutTrackingLiquidity {
     pub provider_transaction_id: Option<String>,
     pub provider_status: Option<String>,
     pub estimated_time_of_arrival: Option<EstimatedTimeOfArrival>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -261,25 +349,25 @@ pub struct PayoutTrackingComplete {
     pub step: TrackingStatus,
     pub status: Option<String>,
     pub transaction_hash: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PayoutTrackingPartnerFee {
     pub step: TrackingStatus,
     pub transaction_hash: Option<String>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<Timestamp>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum PayinPaymentMethod {
-    Ach,
-    Wire,
-    Pix,
-    Spei,
-    Transfers,
-    Pse,
+api_enum! {
+    pub enum PayinPaymentMethod {
+        Ach => "ach",
+        Wire => "wire",
+        Pix => "pix",
+        Spei => "spei",
+        Transfers => "transfers",
+        Pse => "pse",
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -294,3 +382,69 @@ pub struct PayerRules {
     pub pse_phone: Option<String>,
     pub pse_bank_code: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_values_use_wire_names() {
+        assert_eq!(
+            serde_json::to_string(&Network::ArbitrumSepolia).unwrap(),
+            r#""arbitrum_sepolia""#
+        );
+        assert_eq!(Rail::InternationalSwift.as_str(), "international_swift");
+        assert_eq!(
+            serde_json::from_str::<EstimatedTimeOfArrival>(r#""2_business_days""#).unwrap(),
+            EstimatedTimeOfArrival::TwoBusinessDays
+        );
+        assert_eq!("USDC".parse::<Currency>().unwrap(), Currency::USDC);
+    }
+
+    #[test]
+    fn test_unrecognized_values_round_trip() {
+        let network: Network = serde_json::from_str(r#""zksync""#).unwrap();
+        assert_eq!(network, Network::Unknown("zksync".to_string()));
+        assert!(network.is_unknown());
+        assert_eq!(serde_json::to_string(&network).unwrap(), r#""zksync""#);
+
+        let status: TransactionStatus = serde_json::from_str(r#""reversed""#).unwrap();
+        assert_eq!(status.to_string(), "reversed");
+        assert!(!status.is_terminal());
+
+        let rail: Rail = serde_json::from_str(r#""sepa""#).unwrap();
+        assert_eq!(rail.as_str(), "sepa");
+        let currency: Currency = serde_json::from_str(r#""EUR""#).unwrap();
+        assert_eq!(currency, Currency::Unknown("EUR".to_string()));
+        let country: Country = serde_json::from_str(r#""XK""#).unwrap();
+        assert!(country.is_unknown());
+    }
+
+    #[test]
+    fn test_timestamp_keeps_the_raw_text() {
+        let timestamp: Timestamp =
+            serde_json::from_str(r#""2025-01-01T10:00:00.123+02:00""#).unwrap();
+        assert_eq!(timestamp.as_str(), "2025-01-01T10:00:00.123+02:00");
+        assert_eq!(
+            serde_json::to_string(&timestamp).unwrap(),
+            r#""2025-01-01T10:00:00.123+02:00""#
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamp_as_datetime() {
+        use chrono::{TimeZone, Utc};
+
+        let timestamp = Timestamp::from("2025-01-01T10:00:00+02:00");
+        assert_eq!(
+            timestamp.as_datetime(),
+            Some(Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap())
+        );
+        assert_eq!(Timestamp::from("not a date").as_datetime(), None);
+        assert_eq!(
+            Timestamp::from(Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap()).as_str(),
+            "2025-01-01T08:00:00Z"
+        );
+    }
+}
@@ -0,0 +1,409 @@
+//! Client-side checks for bank account inputs
+//!
+//! [`validate`] applies the field rules returned by
+//! [`AvailableResource::get_bank_details`](crate::resources::available::AvailableResource::get_bank_details)
+//! (required fields, `regex` and allowed `items`) together with built-in
+//! checks for CLABE, ABA routing numbers, IBANs and BICs, so an invalid
+//! account is reported field by field before the API is called.
+//!
+//! # Example
+//! ```no_run
+//! # use blindpay::BlindPay;
+//! # use blindpay::resources::bank_accounts::CreateSpeiInput;
+//! # use blindpay::validation;
+//! # async fn example(input: CreateSpeiInput) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = BlindPay::new("api-key", "instance-id")?;
+//! let rules = client.available().get_bank_details(blindpay::Rail::SpeiBitso).await?;
+//! validation::validate(&input, &rules)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{BlindPayError, Result};
+use crate::resources::available::BankDetail;
+use crate::resources::bank_accounts::*;
+use crate::types::{FieldError, Rail};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A bank account creation input that can be validated before it is sent
+pub trait BankAccountInput: Serialize {
+    /// Rail the bank account is created on, used to fetch its field rules
+    fn rail(&self) -> Rail;
+
+    /// Checks that do not depend on the rules returned by the API
+    fn check(&self) -> Vec<FieldError> {
+        Vec::new()
+    }
+}
+
+/// Check `input` against the field rules of its rail and the built-in checks
+///
+/// Pass an empty `rules` slice to run the built-in checks only. Fails with
+/// [`BlindPayError::ValidationFailed`] listing every invalid field.
+pub fn validate<I: BankAccountInput>(input: &I, rules: &[BankDetail]) -> Result<()> {
+    let fields = serde_json::to_value(input)?;
+    let mut errors: Vec<FieldError> = rules
+        .iter()
+        .filter_map(|rule| check_rule(rule, fields.get(&rule.key)))
+        .collect();
+
+    for error in input.check() {
+        if !errors.iter().any(|existing| existing.field == error.field) {
+            errors.push(error);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(BlindPayError::ValidationFailed(errors))
+    }
+}
+
+fn check_rule(rule: &BankDetail, value: Option<&Value>) -> Option<FieldError> {
+    let value = match value {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Number(value)) => value.to_string(),
+        Some(Value::Bool(value)) => value.to_string(),
+        _ => String::new(),
+    };
+
+    if value.is_empty() {
+        return rule
+            .required
+            .then(|| field_error(&rule.key, format!("{} is required", rule.label)));
+    }
+
+    if let Some(items) = &rule.items {
+        let allowed: Vec<&str> = items
+            .iter()
+            .filter(|item| item.is_active != Some(false))
+            .map(|item| item.value.as_str())
+            .collect();
+        if !allowed.contains(&value.as_str()) {
+            return Some(field_error(
+                &rule.key,
+                format!("{} must be one of: {}", rule.label, allowed.join(", ")),
+            ));
+        }
+    }
+
+    // Patterns the regex engine cannot compile are left for the API to enforce
+    match Regex::new(&rule.regex) {
+        Ok(pattern) if !rule.regex.is_empty() && !pattern.is_match(&value) => Some(field_error(
+            &rule.key,
+            format!("{} has an invalid format", rule.label),
+        )),
+        _ => None,
+    }
+}
+
+fn field_error(field: &str, message: impl Into<String>) -> FieldError {
+    FieldError {
+        field: field.to_string(),
+        message: message.into(),
+    }
+}
+
+/// Whether `clabe` is an 18 digit Mexican CLABE with a valid check digit
+pub fn is_valid_clabe(clabe: &str) -> bool {
+    let Some(digits) = digits(clabe, 18) else {
+        return false;
+    };
+    let sum: u32 = digits[..17]
+        .iter()
+        .zip([3, 7, 1].iter().cycle())
+        .map(|(digit, weight)| digit * weight % 10)
+        .sum();
+    (10 - sum % 10) % 10 == digits[17]
+}
+
+/// Whether `routing_number` is a 9 digit ABA routing number with a valid checksum
+pub fn is_valid_aba_routing_number(routing_number: &str) -> bool {
+    let Some(digits) = digits(routing_number, 9) else {
+        return false;
+    };
+    let sum: u32 = digits
+        .iter()
+        .zip([3, 7, 1].iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    sum % 10 == 0
+}
+
+/// Whether `iban` is a well-formed IBAN passing the mod-97 check
+///
+/// Spaces are ignored and letters may be lower case.
+pub fn is_valid_iban(iban: &str) -> bool {
+    let iban: String = iban
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if !(15..=34).contains(&iban.len())
+        || !iban.chars().all(|c| c.is_ascii_alphanumeric())
+        || !iban[..2].chars().all(|c| c.is_ascii_uppercase())
+        || !iban[2..4].chars().all(|c| c.is_ascii_digit())
+    {
+        return false;
+    }
+
+    let remainder = iban[4..]
+        .chars()
+        .chain(iban[..4].chars())
+        .fold(0u32, |remainder, c| {
+            let value = c.to_digit(36).expect("checked alphanumeric");
+            let shift = if value < 10 { 10 } else { 100 };
+            (remainder * shift + value) % 97
+        });
+    remainder == 1
+}
+
+/// Whether `bic` is an 8 or 11 character SWIFT/BIC code
+///
+/// Letters may be lower case.
+pub fn is_valid_bic(bic: &str) -> bool {
+    let bic = bic.to_ascii_uppercase();
+    (bic.len() == 8 || bic.len() == 11)
+        && bic.is_ascii()
+        && bic[..6].chars().all(|c| c.is_ascii_uppercase())
+        && bic[6..].chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn digits(value: &str, len: usize) -> Option<Vec<u32>> {
+    let digits: Option<Vec<u32>> = value.chars().map(|c| c.to_digit(10)).collect();
+    digits.filter(|digits| digits.len() == len)
+}
+
+fn check_routing_number(routing_number: &str) -> Vec<FieldError> {
+    if is_valid_aba_routing_number(routing_number) {
+        Vec::new()
+    } else {
+        vec![field_error(
+            "routing_number",
+            "must be a 9 digit ABA routing number with a valid checksum",
+        )]
+    }
+}
+
+fn check_swift_account(field: &str, account: &str) -> Option<FieldError> {
+    // Accounts starting with a country code are IBANs; others are local account numbers
+    let mut prefix = account.chars().take(2);
+    let looks_like_iban = prefix.clone().count() == 2 && prefix.all(|c| c.is_ascii_alphabetic());
+    (looks_like_iban && !is_valid_iban(account)).then(|| field_error(field, "must be a valid IBAN"))
+}
+
+fn check_bic(field: &str, bic: &str) -> Option<FieldError> {
+    (!is_valid_bic(bic)).then(|| field_error(field, "must be an 8 or 11 character BIC"))
+}
+
+impl BankAccountInput for CreatePixInput {
+    fn rail(&self) -> Rail {
+        Rail::Pix
+    }
+}
+
+impl BankAccountInput for CreateArgentinaTransfersInput {
+    fn rail(&self) -> Rail {
+        Rail::TransfersBitso
+    }
+}
+
+impl BankAccountInput for CreateSpeiInput {
+    fn rail(&self) -> Rail {
+        Rail::SpeiBitso
+    }
+
+    fn check(&self) -> Vec<FieldError> {
+        if self.spei_protocol == SpeiProtocol::Clabe && !is_valid_clabe(&self.spei_clabe) {
+            vec![field_error(
+                "spei_clabe",
+                "must be an 18 digit CLABE with a valid check digit",
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl BankAccountInput for CreateColombiaAchInput {
+    fn rail(&self) -> Rail {
+        Rail::AchCopBitso
+    }
+}
+
+impl BankAccountInput for CreateAchInput {
+    fn rail(&self) -> Rail {
+        Rail::Ach
+    }
+
+    fn check(&self) -> Vec<FieldError> {
+        check_routing_number(&self.routing_number)
+    }
+}
+
+impl BankAccountInput for CreateWireInput {
+    fn rail(&self) -> Rail {
+        Rail::Wire
+    }
+
+    fn check(&self) -> Vec<FieldError> {
+        check_routing_number(&self.routing_number)
+    }
+}
+
+impl BankAccountInput for CreateRtpInput {
+    fn rail(&self) -> Rail {
+        Rail::Rtp
+    }
+
+    fn check(&self) -> Vec<FieldError> {
+        check_routing_number(&self.routing_number)
+    }
+}
+
+impl BankAccountInput for CreateInternationalSwiftInput {
+    fn rail(&self) -> Rail {
+        Rail::InternationalSwift
+    }
+
+    fn check(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        errors.extend(check_bic("swift_code_bic", &self.swift_code_bic));
+        errors.extend(check_swift_account(
+            "swift_account_number_iban",
+            &self.swift_account_number_iban,
+        ));
+        if let Some(bic) = &self.swift_intermediary_bank_swift_code_bic {
+            errors.extend(check_bic("swift_intermediary_bank_swift_code_bic", bic));
+        }
+        if let Some(account) = &self.swift_intermediary_bank_account_number_iban {
+            errors.extend(check_swift_account(
+                "swift_intermediary_bank_account_number_iban",
+                account,
+            ));
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::available::BankDetailItem;
+
+    fn rule(key: &str, regex: &str, required: bool) -> BankDetail {
+        BankDetail {
+            label: key.to_string(),
+            regex: regex.to_string(),
+            key: key.to_string(),
+            items: None,
+            required,
+        }
+    }
+
+    fn spei(clabe: &str) -> CreateSpeiInput {
+        CreateSpeiInput {
+            receiver_id: "re_123".to_string(),
+            beneficiary_name: "Juan Perez".to_string(),
+            name: "Main".to_string(),
+            spei_clabe: clabe.to_string(),
+            spei_institution_code: "40002".to_string(),
+            spei_protocol: SpeiProtocol::Clabe,
+        }
+    }
+
+    fn failed_fields(result: Result<()>) -> Vec<String> {
+        match result {
+            Err(BlindPayError::ValidationFailed(errors)) => {
+                errors.into_iter().map(|error| error.field).collect()
+            }
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert!(is_valid_clabe("002010077777777771"));
+        assert!(!is_valid_clabe("002010077777777772"));
+        assert!(!is_valid_clabe("00201007777777777"));
+
+        assert!(is_valid_aba_routing_number("021000021"));
+        assert!(!is_valid_aba_routing_number("021000022"));
+        assert!(!is_valid_aba_routing_number("02100002a"));
+
+        assert!(is_valid_iban("GB82WEST12345698765432"));
+        assert!(is_valid_iban("de89 3704 0044 0532 0130 00"));
+        assert!(!is_valid_iban("GB82WEST12345698765433"));
+
+        assert!(is_valid_bic("DEUTDEFF"));
+        assert!(is_valid_bic("deutdeff500"));
+        assert!(!is_valid_bic("DEUTDEF"));
+        assert!(!is_valid_bic("DEU1DEFF"));
+    }
+
+    #[test]
+    fn test_validate_applies_rules_and_builtin_checks() {
+        let protocol = BankDetail {
+            items: Some(vec![BankDetailItem {
+                label: "CLABE".to_string(),
+                value: "clabe".to_string(),
+                is_active: Some(true),
+            }]),
+            ..rule("spei_protocol", "", true)
+        };
+        let rules = vec![
+            rule("spei_institution_code", r"^\d{5}$", true),
+            protocol,
+            rule("beneficiary_name", "", true),
+        ];
+
+        assert!(validate(&spei("002010077777777771"), &rules).is_ok());
+
+        let mut input = spei("002010077777777772");
+        input.spei_institution_code = "4000".to_string();
+        input.beneficiary_name = String::new();
+        input.spei_protocol = SpeiProtocol::Debitcard;
+        assert_eq!(
+            failed_fields(validate(&input, &rules)),
+            ["spei_institution_code", "spei_protocol", "beneficiary_name"]
+        );
+
+        // The CLABE check only applies to the clabe protocol
+        assert_eq!(
+            failed_fields(validate(&spei("002010077777777772"), &[])),
+            ["spei_clabe"]
+        );
+    }
+
+    #[test]
+    fn test_swift_accounts_only_need_an_iban_when_they_look_like_one() {
+        let input = CreateInternationalSwiftInput::builder()
+            .receiver_id("re_123")
+            .name("Main")
+            .swift_account_holder_name("Jane Doe")
+            .swift_account_number_iban("123456789")
+            .swift_bank_address_line_1("1 Bank St")
+            .swift_bank_city("London")
+            .swift_bank_country("GB")
+            .swift_bank_name("Bank")
+            .swift_bank_postal_code("EC1A")
+            .swift_bank_state_province_region("London")
+            .swift_beneficiary_address_line_1("2 Home St")
+            .swift_beneficiary_city("London")
+            .swift_beneficiary_country("GB")
+            .swift_beneficiary_postal_code("EC1B")
+            .swift_beneficiary_state_province_region("London")
+            .swift_code_bic("NWBKGB2L")
+            .swift_intermediary_bank_account_number_iban("GB00WEST12345698765432")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            failed_fields(validate(&input, &[])),
+            ["swift_intermediary_bank_account_number_iban"]
+        );
+    }
+}
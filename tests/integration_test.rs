@@ -1,4 +1,21 @@
-use blindpay::{BlindPay, BlindPayError};
+use blindpay::pagination::PaginateOptions;
+use blindpay::polling::{TrackingStage, WaitOptions};
+use blindpay::recording::{Recorder, REDACTED};
+use blindpay::resources::bank_accounts::{
+    BankAccountDetails, CreateAchInput, CreateSpeiInput, SpeiProtocol,
+};
+use blindpay::resources::payouts::{ExecutePayoutOptions, ListPayoutsParams};
+use blindpay::resources::quotes::{ContractInfo, CreateQuoteInput};
+use blindpay::resources::receivers::ListReceiversParams;
+use blindpay::signing::{EvmSigner, SignerError};
+use blindpay::types::{
+    AccountClass, BankAccountType, Currency, CurrencyType, Network, Rail, StablecoinToken,
+    TransactionStatus,
+};
+use blindpay::{Amount, BlindPay, BlindPayError, Money, RetryPolicy};
+use futures_util::TryStreamExt;
+use mockito::Matcher;
+use std::time::Duration;
 
 #[test]
 fn test_client_creation() {
@@ -21,7 +38,7 @@ fn test_missing_instance_id() {
 #[test]
 fn test_client_has_resources() {
     let client = BlindPay::new("test-api-key", "test-instance-id").unwrap();
-    
+
     // Test that all resource methods are accessible
     let _available = client.available();
     let _instances = client.instances();
@@ -35,12 +52,793 @@ fn test_client_has_resources() {
 
 #[tokio::test]
 async fn test_error_handling() {
-    // This test demonstrates error handling
-    // In a real scenario, you would mock the HTTP responses
-    let client = BlindPay::new("invalid-key", "invalid-instance").unwrap();
-    
-    // This should fail with an API error (if network is available)
-    // or a network error (if offline)
-    let result = client.receivers().list().await;
-    assert!(result.is_err());
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/instances/in_123/receivers")
+        .match_header("authorization", "Bearer invalid-key")
+        .with_status(401)
+        .with_body(r#"{"data":null,"error":{"message":"Invalid API key","code":"unauthorized"}}"#)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("invalid-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let err = client.receivers().list(None).await.unwrap_err();
+    match err {
+        BlindPayError::ApiError(err) => {
+            assert!(err.is_unauthorized());
+            assert_eq!(err.message, "Invalid API key");
+            assert_eq!(err.code.as_deref(), Some("unauthorized"));
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+fn payout_json(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "receiver_id": "re_123",
+        "id": id,
+        "status": "completed",
+        "sender_wallet_address": "0x123",
+        "signed_transaction": "",
+        "quote_id": "qu_123",
+        "instance_id": "in_123",
+        "tracking_transaction": { "step": "completed", "status": "found", "transaction_hash": "0xabc", "completed_at": "2025-01-01T00:00:00Z" },
+        "tracking_payment": { "step": "completed", "provider_name": "bitso", "completed_at": "2025-01-01T00:00:00Z" },
+        "tracking_liquidity": { "step": "completed", "completed_at": "2025-01-01T00:00:00Z" },
+        "tracking_complete": { "step": "completed", "status": "completed", "completed_at": "2025-01-01T00:00:00Z" },
+        "tracking_partner_fee": { "step": "completed" },
+        "created_at": "2025-01-01T00:00:00Z",
+        "updated_at": "2025-01-01T00:00:00Z",
+        "network": "polygon",
+        "token": "USDC",
+        "description": "",
+        "sender_amount": 100.0,
+        "receiver_amount": 500.0,
+        "partner_fee_amount": 0.0,
+        "commercial_quotation": 5.0,
+        "blindpay_quotation": 5.0,
+        "total_fee_amount": 1.0,
+        "receiver_local_amount": 500.0,
+        "currency": "BRL"
+    })
+}
+
+fn payouts_page(ids: &[&str], has_more: bool) -> String {
+    serde_json::json!({
+        "data": {
+            "data": ids.iter().map(|id| payout_json(id)).collect::<Vec<_>>(),
+            "pagination": { "has_more": has_more, "next_page": null, "prev_page": null }
+        },
+        "error": null
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_payouts_list_stream_follows_cursors() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/instances/in_123/payouts")
+        .match_query(Matcher::Exact("limit=2".into()))
+        .with_body(payouts_page(&["pa_1", "pa_2"], true))
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/instances/in_123/payouts")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("limit".into(), "2".into()),
+            Matcher::UrlEncoded("starting_after".into(), "pa_2".into()),
+        ]))
+        .with_body(payouts_page(&["pa_3"], false))
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let payouts = client
+        .payouts()
+        .list_all(None, PaginateOptions::new().page_size(2))
+        .await
+        .unwrap();
+    let ids: Vec<_> = payouts.iter().map(|p| p.id.as_str()).collect();
+    assert_eq!(ids, ["pa_1", "pa_2", "pa_3"]);
+    assert_eq!(
+        payouts[0].sender_money(),
+        Money::new(Amount::from(100), Currency::USDC)
+    );
+    assert_eq!(payouts[0].receiver_local_amount.to_string(), "500");
+
+    first.assert_async().await;
+    second.assert_async().await;
+
+    let limited: Vec<_> = client
+        .payouts()
+        .list_stream(None, PaginateOptions::new().page_size(2).max_items(1))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[tokio::test]
+async fn test_response_amounts_stay_exact() {
+    let mut server = mockito::Server::new_async().await;
+    let body = serde_json::json!({ "data": payout_json("pa_1"), "error": null })
+        .to_string()
+        .replace(
+            "\"sender_amount\":100.0",
+            "\"sender_amount\":100.123456789012345678901",
+        );
+    let _get = server
+        .mock("GET", "/instances/in_123/payouts/pa_1")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let payout = client.payouts().get("pa_1").await.unwrap();
+    assert_eq!(
+        payout.sender_amount.to_string(),
+        "100.123456789012345678901"
+    );
+}
+
+#[tokio::test]
+async fn test_payouts_list_sends_filters_on_every_page() {
+    let mut server = mockito::Server::new_async().await;
+    let filters = || {
+        vec![
+            Matcher::UrlEncoded("receiver_id".into(), "re_1".into()),
+            Matcher::UrlEncoded("status".into(), "completed".into()),
+            Matcher::UrlEncoded("network".into(), "polygon".into()),
+            Matcher::UrlEncoded("created_after".into(), "2025-01-01T00:00:00+00:00".into()),
+            Matcher::UrlEncoded("limit".into(), "1".into()),
+        ]
+    };
+    let first = server
+        .mock("GET", "/instances/in_123/payouts")
+        .match_query(Matcher::AllOf(filters()))
+        .with_body(payouts_page(&["pa_1"], true))
+        .expect(1)
+        .create_async()
+        .await;
+    let mut second_filters = filters();
+    second_filters.push(Matcher::UrlEncoded("starting_after".into(), "pa_1".into()));
+    let second = server
+        .mock("GET", "/instances/in_123/payouts")
+        .match_query(Matcher::AllOf(second_filters))
+        .with_body(payouts_page(&["pa_2"], false))
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let params = ListPayoutsParams::new()
+        .receiver_id("re_1")
+        .status(TransactionStatus::Completed)
+        .network(Network::Polygon)
+        .created_after("2025-01-01T00:00:00+00:00");
+    let payouts = client
+        .payouts()
+        .list_all(Some(params), PaginateOptions::new().page_size(1))
+        .await
+        .unwrap();
+    assert_eq!(payouts.len(), 2);
+
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_payout_wait_for_completion_polls_until_terminal() {
+    let mut server = mockito::Server::new_async().await;
+    let mut processing = payout_json("pa_1");
+    processing["status"] = "processing".into();
+    processing["tracking_payment"]["step"] = "processing".into();
+    let envelope = |payout: serde_json::Value| {
+        serde_json::json!({ "data": payout, "error": null }).to_string()
+    };
+
+    let pending = server
+        .mock("GET", "/instances/in_123/payouts/pa_1")
+        .with_body(envelope(processing))
+        .expect(2)
+        .create_async()
+        .await;
+    let done = server
+        .mock("GET", "/instances/in_123/payouts/pa_1")
+        .with_body(envelope(payout_json("pa_1")))
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let options = WaitOptions::new().poll_interval(Duration::from_millis(5));
+    let updates: Vec<_> = client
+        .payouts()
+        .watch("pa_1", options)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[1].changed, [TrackingStage::Payment]);
+    assert_eq!(updates[1].current.status, TransactionStatus::Completed);
+    pending.assert_async().await;
+    done.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bank_account_validate_uses_rail_rules() {
+    let mut server = mockito::Server::new_async().await;
+    let rules = server
+        .mock("GET", "/available/bank-details")
+        .match_query(Matcher::UrlEncoded("rail".into(), "ach".into()))
+        .with_body(
+            serde_json::json!({
+                "data": [
+                    {"label": "Account number", "regex": "^\\d{4,17}$", "key": "account_number", "items": null, "required": true},
+                    {"label": "Routing number", "regex": "^\\d{9}$", "key": "routing_number", "items": null, "required": true}
+                ],
+                "error": null
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let input = CreateAchInput {
+        receiver_id: "re_123".to_string(),
+        name: "Checking".to_string(),
+        account_class: AccountClass::Individual,
+        account_number: "12ab".to_string(),
+        account_type: BankAccountType::Checking,
+        beneficiary_name: "John Doe".to_string(),
+        routing_number: "021000022".to_string(),
+    };
+
+    let err = client
+        .receivers()
+        .bank_accounts()
+        .validate(&input)
+        .await
+        .unwrap_err();
+    let BlindPayError::ValidationFailed(errors) = err else {
+        panic!("expected ValidationFailed, got {:?}", err);
+    };
+    let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, ["account_number", "routing_number"]);
+    rules.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bank_account_create_sends_rail_type() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/instances/in_123/receivers/re_123/bank-accounts")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "type": "spei_bitso",
+            "spei_clabe": "002010077777777771"
+        })))
+        .with_body(
+            serde_json::json!({
+                "data": {
+                    "id": "ba_1",
+                    "type": "spei_bitso",
+                    "name": "Main",
+                    "beneficiary_name": "Juan Perez",
+                    "spei_protocol": "clabe",
+                    "spei_institution_code": "40002",
+                    "spei_clabe": "002010077777777771",
+                    "created_at": "2025-01-01T00:00:00Z"
+                },
+                "error": null
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let account = client
+        .receivers()
+        .bank_accounts()
+        .create(CreateSpeiInput {
+            receiver_id: "re_123".to_string(),
+            beneficiary_name: "Juan Perez".to_string(),
+            name: "Main".to_string(),
+            spei_clabe: "002010077777777771".to_string(),
+            spei_institution_code: "40002".to_string(),
+            spei_protocol: SpeiProtocol::Clabe,
+        })
+        .await
+        .unwrap();
+
+    match account.details() {
+        BankAccountDetails::Spei { clabe, .. } => assert_eq!(clabe, "002010077777777771"),
+        other => panic!("expected SPEI details, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+struct MockSigner {
+    approvals: std::sync::Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl EvmSigner for MockSigner {
+    fn address(&self) -> String {
+        "0xSender".to_string()
+    }
+
+    async fn approve(&self, contract: &ContractInfo) -> Result<String, SignerError> {
+        self.approvals.lock().unwrap().push(format!(
+            "{} {}",
+            contract.blindpay_contract_address, contract.amount
+        ));
+        Ok("0xapproval".to_string())
+    }
+}
+
+fn quote_body(expires_at: std::time::SystemTime) -> String {
+    let expires_at = expires_at
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64;
+    serde_json::json!({
+        "data": {
+            "id": "qu_123",
+            "expires_at": expires_at,
+            "commercial_quotation": 5.0,
+            "blindpay_quotation": 5.0,
+            "receiver_amount": 500,
+            "sender_amount": 100,
+            "contract": {
+                "abi": [],
+                "address": "0xToken",
+                "functionName": "approve",
+                "blindpayContractAddress": "0xBlindPay",
+                "amount": "100000000",
+                "network": { "name": "Base", "chainId": 8453 }
+            }
+        },
+        "error": null
+    })
+    .to_string()
+}
+
+fn quote_input() -> CreateQuoteInput {
+    CreateQuoteInput::builder()
+        .bank_account_id("ba_123")
+        .currency_type(CurrencyType::Sender)
+        .cover_fees(false)
+        .request_amount(100)
+        .network(Network::Base)
+        .token(StablecoinToken::USDC)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_execute_evm_quotes_approves_and_creates_payout() {
+    let mut server = mockito::Server::new_async().await;
+    let quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(quote_body(
+            std::time::SystemTime::now() + Duration::from_secs(300),
+        ))
+        .create_async()
+        .await;
+    let payout = server
+        .mock("POST", "/instances/in_123/payouts/evm")
+        .match_header("idempotency-key", Matcher::Any)
+        .match_body(Matcher::Json(serde_json::json!({
+            "quote_id": "qu_123",
+            "sender_wallet_address": "0xSender"
+        })))
+        .with_body(r#"{"data":{"id":"pa_1","status":"processing","sender_wallet_address":"0xSender","receiver_id":"re_123","tracking_complete":null,"tracking_payment":null,"tracking_transaction":null,"tracking_partner_fee":null,"tracking_liquidity":null},"error":null}"#)
+        .create_async()
+        .await;
+    let fetched = server
+        .mock("GET", "/instances/in_123/payouts/pa_1")
+        .with_body(serde_json::json!({ "data": payout_json("pa_1"), "error": null }).to_string())
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+    let signer = MockSigner {
+        approvals: Default::default(),
+    };
+
+    let executed = client
+        .payouts()
+        .execute_evm(quote_input(), &signer, ExecutePayoutOptions::new())
+        .await
+        .unwrap();
+
+    assert_eq!(executed.quote.id, "qu_123");
+    assert_eq!(executed.approval_transaction, "0xapproval");
+    assert_eq!(executed.payout.id, "pa_1");
+    assert_eq!(*signer.approvals.lock().unwrap(), ["0xBlindPay 100000000"]);
+    quote.assert_async().await;
+    payout.assert_async().await;
+    fetched.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_execute_evm_reports_created_payout_when_fetch_fails() {
+    let mut server = mockito::Server::new_async().await;
+    let _quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(quote_body(
+            std::time::SystemTime::now() + Duration::from_secs(300),
+        ))
+        .create_async()
+        .await;
+    let _payout = server
+        .mock("POST", "/instances/in_123/payouts/evm")
+        .match_header("idempotency-key", "order-42")
+        .with_body(r#"{"data":{"id":"pa_1","status":"processing","sender_wallet_address":"0xSender","receiver_id":"re_123","tracking_complete":null,"tracking_payment":null,"tracking_transaction":null,"tracking_partner_fee":null,"tracking_liquidity":null},"error":null}"#)
+        .create_async()
+        .await;
+    let _fetched = server
+        .mock("GET", "/instances/in_123/payouts/pa_1")
+        .with_status(500)
+        .with_body(r#"{"data":null,"error":{"message":"Internal error"}}"#)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let signer = MockSigner {
+        approvals: Default::default(),
+    };
+
+    let err = client
+        .payouts()
+        .execute_evm(
+            quote_input(),
+            &signer,
+            ExecutePayoutOptions::new().idempotency_key("order-42"),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.created_payout_id(), Some("pa_1"));
+    assert_eq!(err.idempotency_key(), Some("order-42"));
+    match err {
+        BlindPayError::PayoutStatusUnknown { source, .. } => {
+            assert_eq!(source.status().map(|s| s.as_u16()), Some(500))
+        }
+        other => panic!("expected PayoutStatusUnknown, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_execute_evm_returns_idempotency_key_when_create_fails() {
+    let mut server = mockito::Server::new_async().await;
+    let _quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(quote_body(
+            std::time::SystemTime::now() + Duration::from_secs(300),
+        ))
+        .create_async()
+        .await;
+    let failed = server
+        .mock("POST", "/instances/in_123/payouts/evm")
+        .match_header(
+            "idempotency-key",
+            Matcher::Regex("^[0-9a-f-]{36}$".to_string()),
+        )
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let signer = MockSigner {
+        approvals: Default::default(),
+    };
+
+    let err = client
+        .payouts()
+        .execute_evm(quote_input(), &signer, ExecutePayoutOptions::new())
+        .await
+        .unwrap_err();
+    failed.assert_async().await;
+
+    assert_eq!(err.created_payout_id(), None);
+    let key = err.idempotency_key().unwrap().to_string();
+    assert!(matches!(
+        &err,
+        BlindPayError::PayoutStatusUnknown { source, .. } if source.status().map(|s| s.as_u16()) == Some(503)
+    ));
+
+    // Resuming with the key replays the same creation
+    let replayed = server
+        .mock("POST", "/instances/in_123/payouts/evm")
+        .match_header("idempotency-key", key.as_str())
+        .with_body(r#"{"data":{"id":"pa_1","status":"processing","sender_wallet_address":"0xSender","receiver_id":"re_123","tracking_complete":null,"tracking_payment":null,"tracking_transaction":null,"tracking_partner_fee":null,"tracking_liquidity":null},"error":null}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let _fetched = server
+        .mock("GET", "/instances/in_123/payouts/pa_1")
+        .with_body(serde_json::json!({ "data": payout_json("pa_1"), "error": null }).to_string())
+        .create_async()
+        .await;
+
+    let executed = client
+        .payouts()
+        .execute_evm(
+            quote_input(),
+            &signer,
+            ExecutePayoutOptions::new().idempotency_key(key),
+        )
+        .await
+        .unwrap();
+    assert_eq!(executed.payout.id, "pa_1");
+    replayed.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_execute_evm_passes_through_rejected_create() {
+    let mut server = mockito::Server::new_async().await;
+    let _quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(quote_body(
+            std::time::SystemTime::now() + Duration::from_secs(300),
+        ))
+        .create_async()
+        .await;
+    let _rejected = server
+        .mock("POST", "/instances/in_123/payouts/evm")
+        .with_status(400)
+        .with_body(r#"{"data":null,"error":{"message":"Insufficient allowance"}}"#)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+    let signer = MockSigner {
+        approvals: Default::default(),
+    };
+
+    let err = client
+        .payouts()
+        .execute_evm(quote_input(), &signer, ExecutePayoutOptions::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BlindPayError::ApiError(_)));
+    assert_eq!(err.idempotency_key(), None);
+}
+
+#[tokio::test]
+async fn test_execute_evm_refuses_expiring_quote() {
+    let mut server = mockito::Server::new_async().await;
+    let _quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(quote_body(
+            std::time::SystemTime::now() + Duration::from_secs(5),
+        ))
+        .create_async()
+        .await;
+    let payout = server
+        .mock("POST", "/instances/in_123/payouts/evm")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+    let signer = MockSigner {
+        approvals: Default::default(),
+    };
+
+    let err = client
+        .payouts()
+        .execute_evm(quote_input(), &signer, ExecutePayoutOptions::new())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, BlindPayError::QuoteExpired { ref quote_id } if quote_id == "qu_123"));
+    assert!(signer.approvals.lock().unwrap().is_empty());
+    payout.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_recorded_exchanges_replay_without_network() {
+    let cassette =
+        std::env::temp_dir().join(format!("blindpay-cassette-{}.json", std::process::id()));
+    let spei_input = || CreateSpeiInput {
+        receiver_id: "re_123".to_string(),
+        beneficiary_name: "Juan Perez".to_string(),
+        name: "Main".to_string(),
+        spei_clabe: "002010077777777771".to_string(),
+        spei_institution_code: "40002".to_string(),
+        spei_protocol: SpeiProtocol::Clabe,
+    };
+
+    let mut server = mockito::Server::new_async().await;
+    let _create = server
+        .mock("POST", "/instances/in_123/receivers/re_123/bank-accounts")
+        .with_body(
+            serde_json::json!({
+                "data": {
+                    "id": "ba_1",
+                    "type": "spei_bitso",
+                    "name": "Main",
+                    "beneficiary_name": "Juan Perez",
+                    "spei_clabe": "002010077777777771",
+                    "created_at": "2025-01-01T00:00:00Z"
+                },
+                "error": null
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _missing = server
+        .mock("GET", "/instances/in_123/payouts/pa_404")
+        .with_status(404)
+        .with_body(r#"{"data":null,"error":{"message":"Payout not found","code":"not_found"}}"#)
+        .create_async()
+        .await;
+    let _receivers = server
+        .mock("GET", "/instances/in_123/receivers")
+        .match_query(Matcher::UrlEncoded(
+            "email".to_string(),
+            "juan@example.com".to_string(),
+        ))
+        .with_body(r#"{"data":[],"error":null}"#)
+        .create_async()
+        .await;
+    let _details = server
+        .mock("GET", "/available/bank-details")
+        .match_query(Matcher::UrlEncoded("rail".to_string(), "pix".to_string()))
+        .with_body(r#"{"data":[{"label":"PIX key","regex":"","key":"pix_key","items":null,"required":true}],"error":null}"#)
+        .create_async()
+        .await;
+    let by_email = || ListReceiversParams::new().email("juan@example.com");
+
+    let recording = BlindPay::builder()
+        .api_key("sk_live_secret")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .recorder(Recorder::record(&cassette))
+        .build()
+        .unwrap();
+    let bank_accounts = recording.receivers().bank_accounts();
+    bank_accounts.create(spei_input()).await.unwrap();
+    assert!(recording
+        .payouts()
+        .get("pa_404")
+        .await
+        .unwrap_err()
+        .is_not_found());
+    recording.receivers().list(Some(by_email())).await.unwrap();
+    recording
+        .available()
+        .get_bank_details(Rail::Pix)
+        .await
+        .unwrap();
+
+    let written = std::fs::read_to_string(&cassette).unwrap();
+    assert!(!written.contains("sk_live_secret"));
+    assert!(!written.contains("002010077777777771"));
+    assert!(!written.contains("Juan Perez"));
+    assert!(!written.contains("juan%40example.com"));
+    assert!(!written.contains("juan@example.com"));
+    assert!(written.contains(REDACTED));
+    drop(server);
+
+    // Nothing listens on port 9; every answer comes from the cassette
+    let replaying = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url("http://127.0.0.1:9")
+        .recorder(Recorder::replay(&cassette).unwrap())
+        .build()
+        .unwrap();
+    let account = replaying
+        .receivers()
+        .bank_accounts()
+        .create(spei_input())
+        .await
+        .unwrap();
+    assert_eq!(account.id, "ba_1");
+    assert_eq!(account.spei_clabe.as_deref(), Some(REDACTED));
+    assert!(replaying
+        .payouts()
+        .get("pa_404")
+        .await
+        .unwrap_err()
+        .is_not_found());
+    assert!(replaying
+        .receivers()
+        .list(Some(by_email()))
+        .await
+        .unwrap()
+        .data
+        .is_empty());
+    let details = replaying
+        .available()
+        .get_bank_details(Rail::Pix)
+        .await
+        .unwrap();
+    assert_eq!(details[0].key, "pix_key");
+
+    // Each interaction is served once, and unrecorded requests fail
+    let err = replaying.payouts().get("pa_404").await.unwrap_err();
+    assert!(matches!(
+        err,
+        BlindPayError::NoRecordedInteraction { ref method, ref path }
+            if method == "GET" && path == "/instances/in_123/payouts/pa_404"
+    ));
+    std::fs::remove_file(&cassette).unwrap();
 }
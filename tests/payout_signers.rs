@@ -0,0 +1,199 @@
+//! Stellar and Solana payout signers, run against recorded API fixtures
+#![cfg(any(feature = "stellar", feature = "solana"))]
+
+use blindpay::resources::payouts::ExecutePayoutOptions;
+use blindpay::resources::quotes::CreateQuoteInput;
+use blindpay::signing::{SignerError, TransactionSigner};
+use blindpay::types::{CurrencyType, Network, StablecoinToken};
+use blindpay::BlindPay;
+use mockito::Matcher;
+use std::sync::Mutex;
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+/// Wallet that "signs" by tagging the transaction, recording what it saw
+struct FakeWallet {
+    seen: Mutex<Vec<String>>,
+}
+
+impl FakeWallet {
+    fn new() -> Self {
+        Self {
+            seen: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionSigner for FakeWallet {
+    fn address(&self) -> String {
+        "wallet".to_string()
+    }
+
+    async fn sign_transaction(&self, transaction: &str) -> Result<String, SignerError> {
+        self.seen.lock().unwrap().push(transaction.to_string());
+        Ok(format!("signed:{}", transaction))
+    }
+}
+
+fn client(server: &mockito::Server) -> BlindPay {
+    BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap()
+}
+
+fn quote_input(network: Network) -> CreateQuoteInput {
+    CreateQuoteInput::builder()
+        .bank_account_id("ba_123")
+        .currency_type(CurrencyType::Sender)
+        .cover_fees(false)
+        .request_amount(100)
+        .network(network)
+        .token(StablecoinToken::USDC)
+        .build()
+        .unwrap()
+}
+
+fn data_field(fixture_name: &str, field: &str) -> String {
+    let json: serde_json::Value = serde_json::from_str(&fixture(fixture_name)).unwrap();
+    json["data"][field].as_str().unwrap().to_string()
+}
+
+#[cfg(feature = "stellar")]
+#[tokio::test]
+async fn test_stellar_signer_signs_authorization_and_submits_it() {
+    use blindpay::signing::stellar::StellarPayoutSigner;
+
+    let xdr = data_field("stellar_authorize.json", "transaction_hash");
+    let mut server = mockito::Server::new_async().await;
+    let quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(fixture("stellar_quote.json"))
+        .create_async()
+        .await;
+    let authorize = server
+        .mock("POST", "/instances/in_123/payouts/stellar/authorize")
+        .match_body(Matcher::Json(serde_json::json!({
+            "quote_id": "qu_stellar",
+            "sender_wallet_address": "wallet"
+        })))
+        .with_body(fixture("stellar_authorize.json"))
+        .create_async()
+        .await;
+    let submit = server
+        .mock("POST", "/instances/in_123/payouts/stellar")
+        .match_body(Matcher::Json(serde_json::json!({
+            "quote_id": "qu_stellar",
+            "sender_wallet_address": "wallet",
+            "signed_transaction": format!("signed:{}", xdr)
+        })))
+        .with_body(fixture("payout_created.json"))
+        .create_async()
+        .await;
+    let get = server
+        .mock("GET", "/instances/in_123/payouts/pa_signed")
+        .with_body(fixture("payout.json"))
+        .create_async()
+        .await;
+
+    let signer = StellarPayoutSigner::new(FakeWallet::new());
+    let executed = client(&server)
+        .payouts()
+        .execute(
+            quote_input(Network::Stellar),
+            &signer,
+            ExecutePayoutOptions::new(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(executed.approval_transaction, format!("signed:{}", xdr));
+    assert_eq!(executed.payout.id, "pa_signed");
+    for mock in [quote, authorize, submit, get] {
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(feature = "solana")]
+#[tokio::test]
+async fn test_solana_signer_delegates_quoted_amount() {
+    use blindpay::signing::solana::SolanaPayoutSigner;
+
+    let transaction = data_field("solana_prepare_delegate.json", "transaction");
+    let mut server = mockito::Server::new_async().await;
+    let quote = server
+        .mock("POST", "/instances/in_123/quotes")
+        .with_body(fixture("solana_quote.json"))
+        .create_async()
+        .await;
+    let prepare = server
+        .mock("POST", "/instances/in_123/prepare-delegate-solana")
+        .match_body(Matcher::Json(serde_json::json!({
+            "owner_address": "wallet",
+            "token_address": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            "amount": "100000000"
+        })))
+        .with_body(fixture("solana_prepare_delegate.json"))
+        .create_async()
+        .await;
+    let submit = server
+        .mock("POST", "/instances/in_123/payouts/solana")
+        .match_body(Matcher::Json(serde_json::json!({
+            "quote_id": "qu_solana",
+            "sender_wallet_address": "wallet",
+            "signed_transaction": format!("signed:{}", transaction)
+        })))
+        .with_body(fixture("payout_created.json"))
+        .create_async()
+        .await;
+    let get = server
+        .mock("GET", "/instances/in_123/payouts/pa_signed")
+        .with_body(fixture("payout.json"))
+        .create_async()
+        .await;
+
+    let signer = SolanaPayoutSigner::new(FakeWallet::new());
+    let executed = client(&server)
+        .payouts()
+        .execute(
+            quote_input(Network::Solana),
+            &signer,
+            ExecutePayoutOptions::new(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(executed.payout.id, "pa_signed");
+    for mock in [quote, prepare, submit, get] {
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(feature = "stellar")]
+#[tokio::test]
+async fn test_signer_rejects_networks_it_does_not_handle() {
+    use blindpay::signing::stellar::StellarPayoutSigner;
+
+    let server = mockito::Server::new_async().await;
+    let signer = StellarPayoutSigner::new(FakeWallet::new());
+    let err = client(&server)
+        .payouts()
+        .execute(
+            quote_input(Network::Solana),
+            &signer,
+            ExecutePayoutOptions::new(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        blindpay::BlindPayError::InvalidConfiguration(_)
+    ));
+}
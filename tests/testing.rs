@@ -0,0 +1,276 @@
+#![cfg(feature = "test-util")]
+
+use blindpay::polling::{TrackingStage, WaitOptions};
+use blindpay::resources::bank_accounts::{CreateSpeiInput, SpeiProtocol};
+use blindpay::resources::quotes::CreateQuoteInput;
+use blindpay::resources::receivers::{
+    CreateIndividualWithStandardKycInput, IdentificationDocument, KycStatus, ProofOfAddressDocType,
+};
+use blindpay::testing::FakeBlindPay;
+use blindpay::types::{
+    Country, CurrencyType, Network, StablecoinToken, TrackingStatus, TransactionStatus,
+};
+use blindpay::webhooks::{
+    CreateWebhookEndpointInput, WebhookEvent, WebhookPayload, WebhookVerifier,
+};
+use blindpay::{Amount, BlindPay, BlindPayError};
+use std::time::Duration;
+
+fn receiver_input() -> CreateIndividualWithStandardKycInput {
+    CreateIndividualWithStandardKycInput::builder()
+        .email("juan@example.com")
+        .first_name("Juan")
+        .last_name("Perez")
+        .tax_id("PEJJ900101")
+        .date_of_birth("1990-01-01")
+        .country(Country::MX)
+        .address_line_1("Av. Reforma 1")
+        .city("Mexico City")
+        .state_province_region("CDMX")
+        .postal_code("06600")
+        .id_doc_country(Country::MX)
+        .id_doc_type(IdentificationDocument::Passport)
+        .id_doc_front_file("https://example.com/doc.jpg")
+        .proof_of_address_doc_type(ProofOfAddressDocType::UtilityBill)
+        .proof_of_address_doc_file("https://example.com/proof.pdf")
+        .tos_id("tos_123")
+        .build()
+        .unwrap()
+}
+
+/// Create a receiver with a SPEI account, returning both ids
+async fn spei_account(client: &BlindPay) -> (String, String) {
+    let receiver = client
+        .receivers()
+        .create_individual_with_standard_kyc(receiver_input())
+        .await
+        .unwrap();
+    let account = client
+        .receivers()
+        .bank_accounts()
+        .create(CreateSpeiInput {
+            receiver_id: receiver.id.clone(),
+            beneficiary_name: "Juan Perez".to_string(),
+            name: "Main".to_string(),
+            spei_clabe: "002010077777777771".to_string(),
+            spei_institution_code: "40002".to_string(),
+            spei_protocol: SpeiProtocol::Clabe,
+        })
+        .await
+        .unwrap();
+    (receiver.id, account.id)
+}
+
+/// Create a receiver with a SPEI account and quote 100.00 USDC on Base
+async fn quote(client: &BlindPay) -> (String, String) {
+    let (receiver_id, account_id) = spei_account(client).await;
+    let quote = client
+        .quotes()
+        .create(
+            CreateQuoteInput::builder()
+                .bank_account_id(account_id)
+                .currency_type(CurrencyType::Sender)
+                .cover_fees(false)
+                .request_amount(10_000)
+                .network(Network::Base)
+                .token(StablecoinToken::USDC)
+                .build()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    (receiver_id, quote.id)
+}
+
+#[tokio::test]
+async fn test_fake_quotes_price_whole_cents() {
+    let fake = FakeBlindPay::start().await.unwrap();
+    let client = fake.client();
+    let (_, account_id) = spei_account(&client).await;
+    let input = |currency_type: CurrencyType, request_amount: Amount| {
+        CreateQuoteInput::builder()
+            .bank_account_id(account_id.as_str())
+            .currency_type(currency_type)
+            .cover_fees(false)
+            .request_amount(request_amount)
+            .network(Network::Base)
+            .token(StablecoinToken::USDC)
+            .build()
+            .unwrap()
+    };
+
+    let quote = client
+        .quotes()
+        .create(input(CurrencyType::Sender, Amount::from(10_000)))
+        .await
+        .unwrap();
+    assert_eq!(quote.sender_amount, Amount::from(10_000));
+    assert_eq!(quote.receiver_amount, Amount::from(170_000));
+    assert_eq!(quote.contract.unwrap().amount, "100000000");
+
+    let quote = client
+        .quotes()
+        .create(input(CurrencyType::Receiver, Amount::from(170_009)))
+        .await
+        .unwrap();
+    assert_eq!(quote.sender_amount, Amount::from(10_001));
+    assert_eq!(quote.receiver_amount, Amount::from(170_009));
+
+    let err = client
+        .quotes()
+        .create(input(CurrencyType::Sender, Amount::from(-5)))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status().map(|status| status.as_u16()), Some(400));
+}
+
+#[tokio::test]
+async fn test_fake_payout_lifecycle_sends_signed_webhooks() {
+    let fake = FakeBlindPay::start().await.unwrap();
+    let client = fake.client();
+
+    let mut hooks = mockito::Server::new_async().await;
+    let hook = hooks
+        .mock("POST", "/hooks")
+        .with_status(200)
+        .expect(7)
+        .create_async()
+        .await;
+    let endpoints = client.instances().webhook_endpoints();
+    let endpoint = endpoints
+        .create(CreateWebhookEndpointInput {
+            url: format!("{}/hooks", hooks.url()),
+            events: vec![
+                WebhookEvent::ReceiverNew,
+                WebhookEvent::PayoutNew,
+                WebhookEvent::PayoutUpdate,
+                WebhookEvent::PayoutComplete,
+            ],
+        })
+        .await
+        .unwrap();
+    let secret = endpoints.get_secret(&endpoint.id).await.unwrap().key;
+
+    let (receiver_id, quote_id) = quote(&client).await;
+    let created = client
+        .payouts()
+        .create_evm(&quote_id, "0xsender")
+        .await
+        .unwrap();
+    assert_eq!(created.status, TransactionStatus::Processing);
+    assert_eq!(created.receiver_id, receiver_id);
+
+    let payout = fake
+        .advance_payout(
+            &created.id,
+            TrackingStage::Transaction,
+            TrackingStatus::Completed,
+        )
+        .await;
+    assert_eq!(payout.tracking_transaction.step, TrackingStatus::Completed);
+    assert_eq!(payout.tracking_payment.step, TrackingStatus::OnHold);
+
+    fake.complete_payout(&created.id).await;
+    let payout = client.payouts().get(&created.id).await.unwrap();
+    assert_eq!(payout.status, TransactionStatus::Completed);
+    assert_eq!(payout.currency.as_str(), "MXN");
+    assert_eq!(payout.first_name.as_deref(), Some("Juan"));
+
+    let verifier = WebhookVerifier::new(&secret).unwrap();
+    let deliveries = fake.deliveries();
+    let events: Vec<_> = deliveries.iter().map(|d| d.event.as_str()).collect();
+    assert_eq!(
+        events,
+        [
+            "receiver.new",
+            "payout.new",
+            "payout.update",
+            "payout.update",
+            "payout.update",
+            "payout.update",
+            "payout.complete",
+        ]
+    );
+    for delivery in &deliveries {
+        assert_eq!(delivery.status, Some(200));
+        verifier
+            .verify(delivery.payload.as_bytes(), &delivery.headers)
+            .unwrap();
+    }
+    let last = deliveries.last().unwrap();
+    match verifier
+        .verify(last.payload.as_bytes(), &last.headers)
+        .unwrap()
+    {
+        WebhookPayload::PayoutComplete(payout) => {
+            assert_eq!(payout.status, TransactionStatus::Completed)
+        }
+        other => panic!("expected payout.complete, got {:?}", other),
+    }
+    hook.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_fake_payout_can_be_awaited_while_it_advances() {
+    let fake = FakeBlindPay::start().await.unwrap();
+    let client = fake.client();
+    let (_, quote_id) = quote(&client).await;
+    let created = client
+        .payouts()
+        .create_evm(&quote_id, "0xsender")
+        .await
+        .unwrap();
+
+    let payouts = client.payouts();
+    let wait = payouts.wait_for_completion(
+        &created.id,
+        WaitOptions::new().poll_interval(Duration::from_millis(10)),
+    );
+    let (payout, _) = tokio::join!(wait, fake.fail_payout(&created.id));
+    assert_eq!(payout.unwrap().status, TransactionStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_fake_rejects_reused_quotes_and_unknown_keys() {
+    let fake = FakeBlindPay::start().await.unwrap();
+    let client = fake.client();
+    let (receiver_id, quote_id) = quote(&client).await;
+
+    client
+        .payouts()
+        .create_evm(&quote_id, "0xsender")
+        .await
+        .unwrap();
+    let err = client
+        .payouts()
+        .create_evm(&quote_id, "0xsender")
+        .await
+        .unwrap_err();
+    assert_eq!(err.status().map(|s| s.as_u16()), Some(409));
+
+    let err = client
+        .payouts()
+        .create_stellar(blindpay::resources::payouts::CreateStellarPayoutInput {
+            quote_id: "qu_missing".to_string(),
+            sender_wallet_address: "GSENDER".to_string(),
+            signed_transaction: None,
+        })
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+
+    fake.set_kyc_status(&receiver_id, KycStatus::Approved).await;
+    let receiver = client.receivers().get(&receiver_id).await.unwrap();
+    assert_eq!(receiver.kyc_status, KycStatus::Approved);
+
+    let stranger = BlindPay::builder()
+        .api_key("wrong-key")
+        .instance_id(blindpay::testing::INSTANCE_ID)
+        .base_url(fake.url())
+        .build()
+        .unwrap();
+    match stranger.receivers().list(None).await {
+        Err(BlindPayError::ApiError(err)) => assert!(err.is_unauthorized()),
+        other => panic!("expected an unauthorized error, got {:?}", other),
+    }
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "tracing")]
+
+use blindpay::resources::bank_accounts::{CreateSpeiInput, SpeiProtocol};
+use blindpay::{BlindPay, RetryPolicy};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Captured {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture() -> (Captured, tracing::subscriber::DefaultGuard) {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    (captured, tracing::subscriber::set_default(subscriber))
+}
+
+#[tokio::test]
+async fn test_spans_record_call_without_sensitive_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let _create = server
+        .mock("POST", "/instances/in_123/receivers/re_123/bank-accounts")
+        .with_body(
+            serde_json::json!({
+                "data": {
+                    "id": "ba_1",
+                    "type": "spei_bitso",
+                    "name": "Main",
+                    "beneficiary_name": "Juan Perez",
+                    "spei_clabe": "002010077777777771",
+                    "created_at": "2025-01-01T00:00:00Z"
+                },
+                "error": null
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("sk_live_secret")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let (captured, _guard) = capture();
+    client
+        .receivers()
+        .bank_accounts()
+        .create(CreateSpeiInput {
+            receiver_id: "re_123".to_string(),
+            beneficiary_name: "Juan Perez".to_string(),
+            name: "Main".to_string(),
+            spei_clabe: "002010077777777771".to_string(),
+            spei_institution_code: "40002".to_string(),
+            spei_protocol: SpeiProtocol::Clabe,
+        })
+        .await
+        .unwrap();
+
+    let output = captured.text();
+    assert!(output.contains("blindpay.request"));
+    assert!(output.contains("method=POST"));
+    assert!(output.contains("path=/instances/{id}/receivers/{id}/bank-accounts"));
+    assert!(output.contains("resource_ids=in_123,re_123"));
+    assert!(output.contains("status_code=200"));
+    assert!(output.contains("attempt=1"));
+    assert!(output.contains("latency_ms="));
+    assert!(!output.contains("sk_live_secret"));
+    assert!(!output.contains("002010077777777771"));
+    assert!(!output.contains("Juan Perez"));
+}
+
+#[tokio::test]
+async fn test_spans_record_each_retry() {
+    let mut server = mockito::Server::new_async().await;
+    let _failure = server
+        .mock("GET", "/instances/in_123/partner-fees")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let _success = server
+        .mock("GET", "/instances/in_123/partner-fees")
+        .with_body(r#"{"data":[],"error":null}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = BlindPay::builder()
+        .api_key("test-api-key")
+        .instance_id("in_123")
+        .base_url(server.url())
+        .retry_policy(
+            RetryPolicy::default()
+                .base_delay(Duration::from_millis(1))
+                .jitter(false),
+        )
+        .build()
+        .unwrap();
+
+    let (captured, _guard) = capture();
+    client.partner_fees().list().await.unwrap();
+
+    let output = captured.text();
+    assert!(output.contains("attempt finished attempt=1 status_code=503"));
+    assert!(output.contains("attempt finished attempt=2 status_code=200"));
+    assert!(output.contains("path=/instances/{id}/partner-fees"));
+}